@@ -0,0 +1,140 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Compares storage options for `Headers` with the header counts of typical RTSP messages.
+//!
+//! Each benchmark builds the map from header names as they arrive from the parser, looks up the
+//! headers that are usually needed for handling a message and then iterates over all headers in
+//! name order as done when serializing the message. `HashMap` needs a separate sorting step for
+//! the last part, `IndexMap` keeps the insertion order which is also good enough for serializing.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rtsp_types::{HeaderName, HeaderValue};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+
+const COMMON_HEADERS: &[&str] = &[
+    "CSeq",
+    "Session",
+    "Date",
+    "Server",
+    "Range",
+    "RTP-Info",
+    "Content-Length",
+    "Content-Type",
+    "Content-Base",
+    "Cache-Control",
+    "Transport",
+    "Public",
+    "Supported",
+    "Media-Properties",
+    "Accept-Ranges",
+];
+
+fn headers(count: usize) -> Vec<(HeaderName, HeaderValue)> {
+    (0..count)
+        .map(|idx| {
+            let name = match COMMON_HEADERS.get(idx) {
+                Some(name) => HeaderName::from_static_str(name).unwrap(),
+                None => HeaderName::try_from(format!("X-Vendor-Header-{idx}")).unwrap(),
+            };
+
+            (name, HeaderValue::from(format!("value {idx}")))
+        })
+        .collect()
+}
+
+fn lookups() -> Vec<HeaderName> {
+    ["cseq", "session", "content-length", "require"]
+        .iter()
+        .map(|name| HeaderName::try_from(*name).unwrap())
+        .collect()
+}
+
+fn bench_storage(c: &mut Criterion) {
+    let mut group = c.benchmark_group("header_storage");
+
+    for &count in &[5, 10, 20, 30] {
+        let input = headers(count);
+        let lookups = lookups();
+
+        group.bench_with_input(BenchmarkId::new("BTreeMap", count), &input, |b, input| {
+            b.iter(|| {
+                let map = input.iter().cloned().collect::<BTreeMap<_, _>>();
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+                for (name, value) in &map {
+                    black_box((name, value));
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("HashMap", count), &input, |b, input| {
+            b.iter(|| {
+                let map = input.iter().cloned().collect::<HashMap<_, _>>();
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+                let mut sorted = map.iter().collect::<Vec<_>>();
+                sorted.sort_unstable_by(|a, b| a.0.cmp(b.0));
+                for (name, value) in sorted {
+                    black_box((name, value));
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("IndexMap", count), &input, |b, input| {
+            b.iter(|| {
+                let map = input.iter().cloned().collect::<indexmap::IndexMap<_, _>>();
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+                for (name, value) in &map {
+                    black_box((name, value));
+                }
+            })
+        });
+    }
+
+    group.finish();
+
+    // Lookup only, e.g. for a proxy that inspects a few headers without re-serializing
+    let mut group = c.benchmark_group("header_lookup");
+    for &count in &[10, 30] {
+        let input = headers(count);
+        let lookups = lookups();
+
+        let map = input.iter().cloned().collect::<BTreeMap<_, _>>();
+        group.bench_function(BenchmarkId::new("BTreeMap", count), |b| {
+            b.iter(|| {
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+            })
+        });
+
+        let map = input.iter().cloned().collect::<HashMap<_, _>>();
+        group.bench_function(BenchmarkId::new("HashMap", count), |b| {
+            b.iter(|| {
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+            })
+        });
+
+        let map = input.iter().cloned().collect::<indexmap::IndexMap<_, _>>();
+        group.bench_function(BenchmarkId::new("IndexMap", count), |b| {
+            b.iter(|| {
+                for name in &lookups {
+                    black_box(map.get(name));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_storage);
+criterion_main!(benches);
@@ -0,0 +1,103 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Minimal base64 encoding and decoding with the standard alphabet and padding.
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes base64 data, ignoring any whitespace.
+///
+/// Returns `None` if the data is not valid base64.
+#[cfg(feature = "http")]
+pub(crate) fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    let data = data
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect::<Vec<_>>();
+    if data.len() % 4 != 0 {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(data.len() / 4 * 3);
+    for (idx, chunk) in data.chunks(4).enumerate() {
+        let is_last = idx == data.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|b| **b == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return None;
+        }
+
+        let mut n = 0u32;
+        for b in &chunk[..4 - padding] {
+            let v = ALPHABET.iter().position(|c| c == b)?;
+            n = (n << 6) | v as u32;
+        }
+        n <<= 6 * padding as u32;
+
+        decoded.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+    }
+
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foob"), "Zm9vYg==");
+        assert_eq!(encode(b"\xff\xfe\x00"), "//4A");
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_decode() {
+        let data = [
+            (&b""[..], ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"\xff\xfe\x00", "//4A"),
+        ];
+
+        for (plain, encoded) in &data {
+            assert_eq!(decode(encoded.as_bytes()).as_deref(), Some(*plain));
+        }
+
+        assert_eq!(decode(b"Zm9v\r\nYg=="), Some(Vec::from(&b"foob"[..])));
+        assert_eq!(decode(b"Zg="), None);
+        assert_eq!(decode(b"Zg==Zm9v"), None);
+        assert_eq!(decode(b"Z==="), None);
+        assert_eq!(decode(b"Zm9!"), None);
+    }
+}
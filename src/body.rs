@@ -0,0 +1,152 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// Message body with a known representation.
+///
+/// Typed bodies keep their parsed representation and are only serialized with
+/// [`to_bytes`](#method.to_bytes). Their content type is set as `Content-Type` header when building
+/// a message with [`RequestBuilder::body`](struct.RequestBuilder.html#method.body) or
+/// [`ResponseBuilder::body`](struct.ResponseBuilder.html#method.body).
+///
+/// ```rust
+/// let mut params = rtsp_types::TextParameters::new();
+/// params.insert("position", "");
+///
+/// let response = rtsp_types::Response::builder(
+///     rtsp_types::Version::V2_0,
+///     rtsp_types::StatusCode::Ok,
+/// )
+/// .body(rtsp_types::Body::TextParameters(params));
+///
+/// assert_eq!(
+///     response.header(&rtsp_types::headers::CONTENT_TYPE).unwrap(),
+///     "text/parameters",
+/// );
+/// assert_eq!(response.body().as_ref(), b"position\r\n");
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Body {
+    /// No body.
+    Empty,
+    /// Bytes of unknown content type.
+    Bytes(bytes::Bytes),
+    /// SDP session description (`application/sdp`).
+    #[cfg(feature = "sdp")]
+    Sdp(Box<sdp::SessionDescription>),
+    /// Parameters (`text/parameters`).
+    TextParameters(TextParameters),
+}
+
+impl Body {
+    /// Get the content type of the body, or `None` if it's unknown or the body is empty.
+    pub fn content_type(&self) -> Option<headers::ContentType> {
+        let (media_type, media_subtype) = match self {
+            Body::Empty | Body::Bytes(_) => return None,
+            #[cfg(feature = "sdp")]
+            Body::Sdp(_) => (headers::MediaType::Application, "sdp"),
+            Body::TextParameters(_) => (headers::MediaType::Text, "parameters"),
+        };
+
+        Some(headers::ContentType {
+            media_type,
+            media_subtype: String::from(media_subtype),
+            params: Vec::new(),
+        })
+    }
+
+    /// Get the length of the serialized body, i.e. the value of its `Content-Length` header.
+    ///
+    /// For typed bodies this serializes the body.
+    pub fn byte_len(&self) -> usize {
+        match self {
+            Body::Empty => 0,
+            Body::Bytes(bytes) => bytes.len(),
+            #[cfg(feature = "sdp")]
+            Body::Sdp(sdp) => sdp.marshal().len(),
+            Body::TextParameters(params) => params.to_vec().len(),
+        }
+    }
+
+    /// Get the serialized body.
+    ///
+    /// For `Bytes` bodies this does not copy the data.
+    pub fn to_bytes(&self) -> bytes::Bytes {
+        match self {
+            Body::Empty => bytes::Bytes::new(),
+            Body::Bytes(bytes) => bytes.clone(),
+            #[cfg(feature = "sdp")]
+            Body::Sdp(sdp) => bytes::Bytes::from(sdp.marshal()),
+            Body::TextParameters(params) => bytes::Bytes::from(params.to_vec()),
+        }
+    }
+
+    /// Checks if the body is empty.
+    pub fn is_empty(&self) -> bool {
+        self.byte_len() == 0
+    }
+}
+
+impl Default for Body {
+    fn default() -> Self {
+        Body::Empty
+    }
+}
+
+/// Bodies are equal if they have the same representation and serialize to the same bytes.
+impl PartialEq for Body {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Body::Empty, Body::Empty) => true,
+            (Body::Bytes(a), Body::Bytes(b)) => a == b,
+            #[cfg(feature = "sdp")]
+            (Body::Sdp(a), Body::Sdp(b)) => a.marshal() == b.marshal(),
+            (Body::TextParameters(a), Body::TextParameters(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Body {}
+
+impl From<bytes::Bytes> for Body {
+    fn from(v: bytes::Bytes) -> Self {
+        Body::Bytes(v)
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(v: Vec<u8>) -> Self {
+        Body::Bytes(bytes::Bytes::from(v))
+    }
+}
+
+impl<'a> From<&'a [u8]> for Body {
+    fn from(v: &'a [u8]) -> Self {
+        Body::Bytes(bytes::Bytes::copy_from_slice(v))
+    }
+}
+
+impl From<Empty> for Body {
+    fn from(_: Empty) -> Self {
+        Body::Empty
+    }
+}
+
+impl From<Body> for bytes::Bytes {
+    fn from(v: Body) -> bytes::Bytes {
+        match v {
+            Body::Bytes(bytes) => bytes,
+            v => v.to_bytes(),
+        }
+    }
+}
+
+impl From<Body> for Vec<u8> {
+    fn from(v: Body) -> Vec<u8> {
+        bytes::Bytes::from(v).to_vec()
+    }
+}
@@ -36,6 +36,7 @@ pub const IF_MODIFIED_SINCE: HeaderName =
 pub const IF_NONE_MATCH: HeaderName = HeaderName::from_static_str_unchecked("If-None-Match");
 pub const LAST_MODIFIED: HeaderName = HeaderName::from_static_str_unchecked("Last-Modified");
 pub const LOCATION: HeaderName = HeaderName::from_static_str_unchecked("Location");
+pub const MAX_FORWARDS: HeaderName = HeaderName::from_static_str_unchecked("Max-Forwards");
 pub const MEDIA_PROPERTIES: HeaderName = HeaderName::from_static_str_unchecked("Media-Properties");
 pub const MEDIA_RANGE: HeaderName = HeaderName::from_static_str_unchecked("Media-Range");
 pub const MTAG: HeaderName = HeaderName::from_static_str_unchecked("MTag");
@@ -70,3 +71,133 @@ pub const UNSUPPORTED: HeaderName = HeaderName::from_static_str_unchecked("Unsup
 pub const USER_AGENT: HeaderName = HeaderName::from_static_str_unchecked("User-Agent");
 pub const VIA: HeaderName = HeaderName::from_static_str_unchecked("Via");
 pub const WWW_AUTHENTICATE: HeaderName = HeaderName::from_static_str_unchecked("WWW-Authenticate");
+
+/// All standard header names, sorted by their lowercase name.
+///
+/// See [`HeaderName::lookup`](struct.HeaderName.html#method.lookup).
+pub static STANDARD_HEADERS: &[(&str, &HeaderName)] = &[
+    ("accept", &ACCEPT),
+    ("accept-credentials", &ACCEPT_CREDENTIALS),
+    ("accept-encoding", &ACCEPT_ENCODING),
+    ("accept-language", &ACCEPT_LANGUAGE),
+    ("accept-ranges", &ACCEPT_RANGES),
+    ("allow", &ALLOW),
+    ("authentication-info", &AUTHENTICATION_INFO),
+    ("authorization", &AUTHORIZATION),
+    ("bandwidth", &BANDWIDTH),
+    ("blocksize", &BLOCKSIZE),
+    ("cache-control", &CACHE_CONTROL),
+    ("connection", &CONNECTION),
+    ("connection-credentials", &CONNECTION_CREDENTIALS),
+    ("content-base", &CONTENT_BASE),
+    ("content-encoding", &CONTENT_ENCODING),
+    ("content-language", &CONTENT_LANGUAGE),
+    ("content-length", &CONTENT_LENGTH),
+    ("content-location", &CONTENT_LOCATION),
+    ("content-type", &CONTENT_TYPE),
+    ("cseq", &CSEQ),
+    ("date", &DATE),
+    ("expires", &EXPIRES),
+    ("from", &FROM),
+    ("if-match", &IF_MATCH),
+    ("if-modified-since", &IF_MODIFIED_SINCE),
+    ("if-none-match", &IF_NONE_MATCH),
+    ("last-modified", &LAST_MODIFIED),
+    ("location", &LOCATION),
+    ("max-forwards", &MAX_FORWARDS),
+    ("media-properties", &MEDIA_PROPERTIES),
+    ("media-range", &MEDIA_RANGE),
+    ("mtag", &MTAG),
+    ("notify-reason", &NOTIFY_REASON),
+    ("pipelined-requests", &PIPELINED_REQUESTS),
+    ("proxy-authenticate", &PROXY_AUTHENTICATE),
+    ("proxy-authentication-info", &PROXY_AUTHENTICATION_INFO),
+    ("proxy-authorization", &PROXY_AUTHORIZATION),
+    ("proxy-require", &PROXY_REQUIRE),
+    ("proxy-supported", &PROXY_SUPPORTED),
+    ("public", &PUBLIC),
+    ("range", &RANGE),
+    ("referrer", &REFERRER),
+    ("request-status", &REQUEST_STATUS),
+    ("require", &REQUIRE),
+    ("retry-after", &RETRY_AFTER),
+    ("rtp-info", &RTP_INFO),
+    ("scale", &SCALE),
+    ("seek-style", &SEEK_STYLE),
+    ("server", &SERVER),
+    ("session", &SESSION),
+    ("speed", &SPEED),
+    ("supported", &SUPPORTED),
+    ("terminate-reason", &TERMINATE_REASON),
+    ("timestamp", &TIMESTAMP),
+    ("transport", &TRANSPORT),
+    ("unsupported", &UNSUPPORTED),
+    ("user-agent", &USER_AGENT),
+    ("via", &VIA),
+    ("www-authenticate", &WWW_AUTHENTICATE),
+];
+
+/// All standard header names, in the same order as [`STANDARD_HEADERS`](static.STANDARD_HEADERS.html).
+///
+/// See [`HeaderName::all_standard`](struct.HeaderName.html#method.all_standard).
+pub static ALL_STANDARD: &[HeaderName] = &[
+    ACCEPT,
+    ACCEPT_CREDENTIALS,
+    ACCEPT_ENCODING,
+    ACCEPT_LANGUAGE,
+    ACCEPT_RANGES,
+    ALLOW,
+    AUTHENTICATION_INFO,
+    AUTHORIZATION,
+    BANDWIDTH,
+    BLOCKSIZE,
+    CACHE_CONTROL,
+    CONNECTION,
+    CONNECTION_CREDENTIALS,
+    CONTENT_BASE,
+    CONTENT_ENCODING,
+    CONTENT_LANGUAGE,
+    CONTENT_LENGTH,
+    CONTENT_LOCATION,
+    CONTENT_TYPE,
+    CSEQ,
+    DATE,
+    EXPIRES,
+    FROM,
+    IF_MATCH,
+    IF_MODIFIED_SINCE,
+    IF_NONE_MATCH,
+    LAST_MODIFIED,
+    LOCATION,
+    MAX_FORWARDS,
+    MEDIA_PROPERTIES,
+    MEDIA_RANGE,
+    MTAG,
+    NOTIFY_REASON,
+    PIPELINED_REQUESTS,
+    PROXY_AUTHENTICATE,
+    PROXY_AUTHENTICATION_INFO,
+    PROXY_AUTHORIZATION,
+    PROXY_REQUIRE,
+    PROXY_SUPPORTED,
+    PUBLIC,
+    RANGE,
+    REFERRER,
+    REQUEST_STATUS,
+    REQUIRE,
+    RETRY_AFTER,
+    RTP_INFO,
+    SCALE,
+    SEEK_STYLE,
+    SERVER,
+    SESSION,
+    SPEED,
+    SUPPORTED,
+    TERMINATE_REASON,
+    TIMESTAMP,
+    TRANSPORT,
+    UNSUPPORTED,
+    USER_AGENT,
+    VIA,
+    WWW_AUTHENTICATE,
+];
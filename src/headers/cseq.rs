@@ -8,6 +8,35 @@ use super::*;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CSeq(u32);
 
+impl CSeq {
+    /// Returns the sequence number for the next request, wrapping around on overflow.
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::CSeq;
+    ///
+    /// assert_eq!(CSeq::from(1).next(), CSeq::from(2));
+    /// assert_eq!(CSeq::from(u32::MAX).next(), CSeq::from(0));
+    /// ```
+    pub fn next(self) -> CSeq {
+        self + 1
+    }
+}
+
+/// Adds to the sequence number, wrapping around on overflow.
+impl std::ops::Add<u32> for CSeq {
+    type Output = CSeq;
+
+    fn add(self, rhs: u32) -> CSeq {
+        CSeq(self.0.wrapping_add(rhs))
+    }
+}
+
+impl std::ops::AddAssign<u32> for CSeq {
+    fn add_assign(&mut self, rhs: u32) {
+        *self = *self + rhs;
+    }
+}
+
 impl std::ops::Deref for CSeq {
     type Target = u32;
 
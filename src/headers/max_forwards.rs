@@ -0,0 +1,148 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::convert::TryFrom;
+
+/// `Max-Forwards` header ([RFC 7826 section 18.30](https://tools.ietf.org/html/rfc7826#section-18.30)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxForwards(u8);
+
+impl MaxForwards {
+    /// Returns the value for the next hop.
+    ///
+    /// If the value is already zero then the request must not be forwarded anymore and an error
+    /// is returned.
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::MaxForwards;
+    ///
+    /// assert_eq!(MaxForwards::from(1).decrement(), Ok(MaxForwards::from(0)));
+    /// assert!(MaxForwards::from(0).decrement().is_err());
+    /// ```
+    pub fn decrement(self) -> Result<MaxForwards, MaxForwardsError> {
+        self.0
+            .checked_sub(1)
+            .map(MaxForwards)
+            .ok_or(MaxForwardsError)
+    }
+}
+
+impl std::ops::Deref for MaxForwards {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for MaxForwards {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<u8> for MaxForwards {
+    fn as_ref(&self) -> &u8 {
+        &self.0
+    }
+}
+
+impl AsMut<u8> for MaxForwards {
+    fn as_mut(&mut self) -> &mut u8 {
+        &mut self.0
+    }
+}
+
+impl From<u8> for MaxForwards {
+    fn from(v: u8) -> MaxForwards {
+        MaxForwards(v)
+    }
+}
+
+impl From<MaxForwards> for u8 {
+    fn from(v: MaxForwards) -> u8 {
+        v.0
+    }
+}
+
+impl<'a> TryFrom<&'a HeaderValue> for MaxForwards {
+    type Error = HeaderParseError;
+
+    fn try_from(value: &'a HeaderValue) -> Result<Self, HeaderParseError> {
+        let value = value.as_str().trim();
+
+        // Only plain digits are allowed, without a sign
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(HeaderParseError);
+        }
+
+        value
+            .parse::<u8>()
+            .map(MaxForwards)
+            .map_err(|_| HeaderParseError)
+    }
+}
+
+impl From<MaxForwards> for HeaderValue {
+    fn from(v: MaxForwards) -> HeaderValue {
+        HeaderValue::from(v.0.to_string())
+    }
+}
+
+impl super::TypedHeader for MaxForwards {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&MAX_FORWARDS) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        MaxForwards::try_from(header).map(Some)
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(MAX_FORWARDS, HeaderValue::from(*self));
+    }
+}
+
+/// Error returned by [`MaxForwards::decrement`](struct.MaxForwards.html#method.decrement) if the
+/// value is already zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxForwardsError;
+
+impl std::error::Error for MaxForwardsError {}
+
+impl std::fmt::Display for MaxForwardsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Max-Forwards exceeded")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_forwards() {
+        let mut headers = Headers::new();
+        headers.insert(MAX_FORWARDS, "70");
+        assert_eq!(
+            headers.get_typed::<MaxForwards>(),
+            Ok(Some(MaxForwards(70)))
+        );
+
+        for invalid in &["", "-1", "+1", "256", "abc"] {
+            headers.insert(MAX_FORWARDS, *invalid);
+            assert_eq!(headers.get_typed::<MaxForwards>(), Err(HeaderParseError));
+        }
+
+        headers.insert_typed(&MaxForwards(69));
+        assert_eq!(headers.get(&MAX_FORWARDS).unwrap(), "69");
+    }
+}
@@ -22,6 +22,7 @@ pub mod content_length;
 pub mod content_type;
 pub mod cseq;
 pub mod features;
+pub mod max_forwards;
 pub mod media_properties;
 pub mod media_range;
 pub mod notify_reason;
@@ -37,6 +38,8 @@ pub mod speed;
 pub mod supported;
 pub mod transport;
 pub mod unsupported;
+pub mod via;
+pub mod www_authenticate;
 
 pub use accept::{Accept, MediaType, MediaTypeRange};
 pub use accept_ranges::{AcceptRanges, RangeUnit};
@@ -44,21 +47,27 @@ pub use allow::Allow;
 pub use content_length::ContentLength;
 pub use content_type::ContentType;
 pub use cseq::CSeq;
+pub use max_forwards::{MaxForwards, MaxForwardsError};
 pub use media_properties::{MediaProperties, MediaProperty};
 pub use media_range::MediaRange;
 pub use notify_reason::NotifyReason;
 pub use pipelined_requests::PipelinedRequests;
 pub use public::Public;
+#[cfg(feature = "chrono")]
+pub use range::AbsoluteTime;
 pub use range::{NptRange, NptTime, Range, SmpteRange, SmpteTime, SmpteType, UtcRange, UtcTime};
 pub use require::Require;
 pub use rtp_info::RtpInfos;
 pub use scale::Scale;
 pub use seek_style::SeekStyle;
-pub use session::Session;
+pub use session::{Session, SessionError};
 pub use speed::Speed;
 pub use supported::Supported;
 pub use transport::{
-    OtherTransport, RtpLowerTransport, RtpProfile, RtpTransport, RtpTransportParameters, Transport,
+    ChannelMap, InvalidMulticastAddress, OtherTransport, RtpLowerTransport, RtpProfile,
+    RtpTransport, RtpTransportParameters, SsrcDiagnostic, Transport, TransportBuilder,
     TransportMode, TransportParameters, Transports,
 };
 pub use unsupported::Unsupported;
+pub use via::{Via, ViaEntry};
+pub use www_authenticate::{Challenge, WwwAuthenticate};
@@ -5,6 +5,7 @@
 use super::*;
 
 use super::parser_helpers::split_once;
+use std::borrow::Cow;
 use std::fmt;
 
 /// `Range` header ([RFC 7826 section 18.40](https://tools.ietf.org/html/rfc7826#section-18.40)).
@@ -20,6 +21,43 @@ pub enum Range {
     Other(String),
 }
 
+impl Range {
+    /// Duration of the range in seconds.
+    ///
+    /// This is only known for NPT ranges with a numeric start and end, see
+    /// [`NptRange::duration`](enum.NptRange.html#method.duration).
+    pub fn duration(&self) -> Option<f64> {
+        match self {
+            Range::Npt(r) => r.duration(),
+            _ => None,
+        }
+    }
+
+    /// Checks if the NPT position in seconds is inside this range.
+    ///
+    /// See [`NptRange::contains`](enum.NptRange.html#method.contains). This always returns
+    /// `false` for non-NPT ranges.
+    pub fn contains(&self, position: f64) -> bool {
+        match self {
+            Range::Npt(r) => r.contains(position),
+            _ => false,
+        }
+    }
+
+    /// Shifts the NPT range by `offset` seconds.
+    ///
+    /// See [`NptRange::shifted_by`](enum.NptRange.html#method.shifted_by). This always returns
+    /// `None` for non-NPT ranges.
+    pub fn shifted_by(&self, offset: f64) -> Option<Range> {
+        match self {
+            Range::Npt(r) => r.shifted_by(offset).map(Range::Npt),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes the range as used in the `Range` header, e.g. `npt=10-20`, `smpte=10:07:00-` or
+/// `clock=19961108T142300Z-`.
 impl fmt::Display for Range {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -60,6 +98,67 @@ pub enum NptRange {
     To(NptTime),
 }
 
+impl NptRange {
+    /// Duration of the range in seconds.
+    ///
+    /// Returns `None` for open-ended ranges or if either end is `now`.
+    pub fn duration(&self) -> Option<f64> {
+        match self {
+            NptRange::FromTo(f, t) => Some(t.as_seconds()? - f.as_seconds()?),
+            _ => None,
+        }
+    }
+
+    /// Checks if the position in seconds is inside this range, including both ends.
+    ///
+    /// The empty range contains all positions, open-ended ranges are unbounded at the open end.
+    /// Ranges with `now` as one of the ends don't contain any position.
+    pub fn contains(&self, position: f64) -> bool {
+        let (from, to) = match self {
+            NptRange::Empty => return true,
+            NptRange::From(f) => (f.as_seconds(), Some(f64::INFINITY)),
+            NptRange::FromTo(f, t) => (f.as_seconds(), t.as_seconds()),
+            NptRange::To(t) => (Some(0.0), t.as_seconds()),
+        };
+
+        match (from, to) {
+            (Some(from), Some(to)) => from <= position && position <= to,
+            _ => false,
+        }
+    }
+
+    /// Shifts both ends of the range by `offset` seconds, which can be negative.
+    ///
+    /// Ends that would become negative are clamped to zero. `None` is returned for the empty
+    /// range, ranges with `now` as one of the ends and if the end of the range would be before
+    /// its start.
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::{NptRange, NptTime};
+    ///
+    /// let range = NptRange::FromTo(NptTime::Seconds(10, None), NptTime::Seconds(20, None));
+    /// assert_eq!(
+    ///     range.shifted_by(-15.0),
+    ///     Some(NptRange::FromTo(NptTime::Seconds(0, None), NptTime::Seconds(5, None))),
+    /// );
+    /// ```
+    pub fn shifted_by(&self, offset: f64) -> Option<NptRange> {
+        match self {
+            NptRange::Empty => None,
+            NptRange::From(f) => Some(NptRange::From(f.shifted_by(offset)?)),
+            NptRange::FromTo(f, t) => {
+                let (f, t) = (f.shifted_by(offset)?, t.shifted_by(offset)?);
+                if t.as_seconds()? < f.as_seconds()? {
+                    return None;
+                }
+
+                Some(NptRange::FromTo(f, t))
+            }
+            NptRange::To(t) => Some(NptRange::To(t.shifted_by(offset)?)),
+        }
+    }
+}
+
 impl fmt::Display for NptRange {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -114,6 +213,56 @@ pub enum NptTime {
     Hms(u64, u8, u8, Option<u32>),
 }
 
+impl NptTime {
+    /// Time in seconds.
+    ///
+    /// Returns `None` for `now`.
+    pub fn as_seconds(&self) -> Option<f64> {
+        match *self {
+            NptTime::Now => None,
+            NptTime::Seconds(seconds, nanoseconds) => {
+                Some(seconds as f64 + nanoseconds.unwrap_or(0) as f64 / 1_000_000_000.0)
+            }
+            NptTime::Hms(hours, minutes, seconds, nanoseconds) => Some(
+                hours as f64 * 3600.0
+                    + minutes as f64 * 60.0
+                    + seconds as f64
+                    + nanoseconds.unwrap_or(0) as f64 / 1_000_000_000.0,
+            ),
+        }
+    }
+
+    /// Shifts the time by `offset` seconds, clamping at zero.
+    ///
+    /// The representation of the time is kept. Returns `None` for `now` or if the result is
+    /// not a finite number.
+    fn shifted_by(&self, offset: f64) -> Option<NptTime> {
+        let time = (self.as_seconds()? + offset).max(0.0);
+        if !time.is_finite() {
+            return None;
+        }
+
+        let seconds = time.trunc() as u64;
+        let nanoseconds = ((time.fract() * 1_000_000_000.0).round() as u32).min(999_999_999);
+
+        let nanoseconds = match *self {
+            NptTime::Seconds(_, None) | NptTime::Hms(_, _, _, None) if nanoseconds == 0 => None,
+            _ => Some(nanoseconds),
+        };
+
+        match *self {
+            NptTime::Now => None,
+            NptTime::Seconds(..) => Some(NptTime::Seconds(seconds, nanoseconds)),
+            NptTime::Hms(..) => Some(NptTime::Hms(
+                seconds / 3600,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+                nanoseconds,
+            )),
+        }
+    }
+}
+
 impl fmt::Display for NptTime {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -224,8 +373,6 @@ impl std::str::FromStr for SmpteRange {
                 return Ok(SmpteRange::Empty(ty));
             }
 
-            let range = range.strip_prefix('=').ok_or(HeaderParseError)?;
-
             let (from, to) = split_once(range, '-').ok_or(HeaderParseError)?;
             let from = if from.is_empty() { None } else { Some(from) };
             let to = if to.is_empty() { None } else { Some(to) };
@@ -300,6 +447,84 @@ pub struct SmpteTime {
     pub frames: Option<(u8, Option<u8>)>,
 }
 
+impl SmpteTime {
+    /// Converts the timecode to seconds for the given frame rate.
+    ///
+    /// For 29.97 frames per second (`smpte-30-drop`) drop-frame timecodes are assumed, i.e. frame
+    /// numbers 0 and 1 are skipped at the start of each minute except for every tenth minute.
+    pub fn as_seconds(&self, frame_rate: f64) -> f64 {
+        let (frames, subframes) = match self.frames {
+            None => (0, 0),
+            Some((frames, subframes)) => (frames, subframes.unwrap_or(0)),
+        };
+        let subframes = subframes as f64 / 100.0;
+
+        if is_drop_frame_rate(frame_rate) {
+            let total_minutes = 60 * self.hours as u64 + self.minutes as u64;
+            let frame_number = 108_000 * self.hours as u64
+                + 1_800 * self.minutes as u64
+                + 30 * self.seconds as u64
+                + frames as u64;
+            let frame_number = frame_number - 2 * (total_minutes - total_minutes / 10);
+
+            (frame_number as f64 + subframes) * 1001.0 / 30000.0
+        } else {
+            let seconds =
+                3600.0 * self.hours as f64 + 60.0 * self.minutes as f64 + self.seconds as f64;
+
+            seconds + (frames as f64 + subframes) / frame_rate
+        }
+    }
+
+    /// Creates a timecode from seconds for the given frame rate.
+    ///
+    /// For 29.97 frames per second (`smpte-30-drop`) drop-frame timecodes are created. Negative
+    /// values are handled as zero.
+    pub fn from_seconds(secs: f64, frame_rate: f64) -> SmpteTime {
+        // Tolerance for rounding errors in the floating point calculations
+        const EPSILON: f64 = 1e-6;
+
+        let secs = secs.max(0.0);
+        let drop_frame = is_drop_frame_rate(frame_rate);
+
+        let (total_frames, nominal_rate) = if drop_frame {
+            (secs * 30000.0 / 1001.0 + EPSILON, 30)
+        } else {
+            (
+                secs * frame_rate + EPSILON,
+                frame_rate.round().max(1.0) as u64,
+            )
+        };
+
+        let mut frame_number = total_frames.floor() as u64;
+        let subframes = ((total_frames - total_frames.floor()) * 100.0) as u8;
+
+        if drop_frame {
+            // Add back the dropped frame numbers
+            let tens = frame_number / 17_982;
+            let rem = frame_number % 17_982;
+            frame_number += 18 * tens;
+            if rem > 1 {
+                frame_number += 2 * ((rem - 2) / 1_798);
+            }
+        }
+
+        let frames = (frame_number % nominal_rate) as u8;
+        let total_seconds = frame_number / nominal_rate;
+
+        SmpteTime {
+            hours: std::cmp::min(total_seconds / 3600, u8::MAX as u64) as u8,
+            minutes: ((total_seconds / 60) % 60) as u8,
+            seconds: (total_seconds % 60) as u8,
+            frames: Some((frames, if subframes > 0 { Some(subframes) } else { None })),
+        }
+    }
+}
+
+fn is_drop_frame_rate(frame_rate: f64) -> bool {
+    (frame_rate - 30000.0 / 1001.0).abs() < 0.01
+}
+
 impl fmt::Display for SmpteTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.frames {
@@ -472,8 +697,18 @@ impl std::str::FromStr for UtcTime {
         let (date, time) = split_once(s, 'T').ok_or(HeaderParseError)?;
         let time = time.strip_suffix('Z').ok_or(HeaderParseError)?;
 
+        // Also accept the ISO 8601 extended format `YYYY-MM-DDTHH:MM:SS[.fraction]Z`
+        let date = match date.as_bytes() {
+            [_, _, _, _, b'-', _, _, b'-', _, _] => Cow::Owned(date.replace('-', "")),
+            _ => Cow::Borrowed(date),
+        };
+        let time = match time.as_bytes() {
+            [_, _, b':', _, _, b':', _, _, ..] => Cow::Owned(time.replacen(':', "", 2)),
+            _ => Cow::Borrowed(time),
+        };
+
         let date = date.parse::<u32>().map_err(|_| HeaderParseError)?;
-        let (time, nanoseconds) = if let Some((time, subseconds)) = split_once(time, '.') {
+        let (time, nanoseconds) = if let Some((time, subseconds)) = split_once(&time, '.') {
             let time = time.parse::<u32>().map_err(|_| HeaderParseError)?;
             let digits = subseconds.len();
             if digits > 9 || digits == 0 {
@@ -498,6 +733,90 @@ impl std::str::FromStr for UtcTime {
     }
 }
 
+/// Absolute time as used in the `clock` form of the `Range` header.
+///
+/// This is a wrapper around `chrono::DateTime<Utc>` that can be parsed from and converted to
+/// the RTSP subset of ISO 8601 UTC timestamps, e.g. `19961108T143720.25Z` or
+/// `1996-11-08T14:37:20.25Z`.
+///
+/// This is only available with the `chrono` feature.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AbsoluteTime(pub chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<UtcTime> for AbsoluteTime {
+    type Error = HeaderParseError;
+
+    fn try_from(time: UtcTime) -> Result<Self, HeaderParseError> {
+        use chrono::TimeZone;
+
+        let date = chrono::NaiveDate::from_ymd_opt(
+            (time.date / 10000) as i32,
+            (time.date / 100) % 100,
+            time.date % 100,
+        )
+        .ok_or(HeaderParseError)?;
+        let date_time = date
+            .and_hms_nano_opt(
+                time.time / 10000,
+                (time.time / 100) % 100,
+                time.time % 100,
+                time.nanoseconds.unwrap_or(0),
+            )
+            .ok_or(HeaderParseError)?;
+
+        Ok(AbsoluteTime(chrono::Utc.from_utc_datetime(&date_time)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<AbsoluteTime> for UtcTime {
+    fn from(time: AbsoluteTime) -> Self {
+        use chrono::{Datelike, Timelike};
+
+        let t = time.0;
+        let nanoseconds = t.nanosecond();
+
+        UtcTime {
+            date: t.year().max(0) as u32 * 10000 + t.month() * 100 + t.day(),
+            time: t.hour() * 10000 + t.minute() * 100 + t.second(),
+            nanoseconds: if nanoseconds != 0 {
+                Some(nanoseconds)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl fmt::Display for AbsoluteTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <UtcTime as fmt::Display>::fmt(&UtcTime::from(*self), f)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl std::str::FromStr for AbsoluteTime {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        use std::convert::TryFrom;
+
+        AbsoluteTime::try_from(s.parse::<UtcTime>()?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<'a> std::convert::TryFrom<&'a str> for AbsoluteTime {
+    type Error = HeaderParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, HeaderParseError> {
+        s.parse()
+    }
+}
+
 impl super::TypedHeader for Range {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
         let headers = headers.as_ref();
@@ -582,4 +901,134 @@ mod tests {
             assert_eq!(range, serialized.unwrap_or(header), "{header}");
         }
     }
+
+    #[test]
+    fn test_range_display() {
+        let ranges = [
+            "npt",
+            "npt=now-",
+            "npt=123-456",
+            "smpte",
+            "smpte=10:07:00-10:07:33:05.01",
+            "smpte-25=-10:07:33",
+            "smpte-30-drop=10:07:00-",
+            "clock",
+            "clock=19961108T143720.250000000Z-",
+            "clock=19961108T142300Z-19961108T143520Z",
+            "foo=bar",
+        ];
+
+        for s in &ranges {
+            let range = s
+                .parse::<Range>()
+                .unwrap_or_else(|_| panic!("couldn't parse {}", s));
+            assert_eq!(range.to_string(), *s);
+            assert_eq!(range.to_string().parse::<Range>().unwrap(), range);
+        }
+    }
+
+    #[test]
+    fn test_npt_duration() {
+        let range = "npt=10-25.5".parse::<Range>().unwrap();
+        assert_eq!(range.duration(), Some(15.5));
+        assert!(range.contains(10.0));
+        assert!(range.contains(20.0));
+        assert!(!range.contains(26.0));
+
+        let range = "npt=00:01:00-00:02:30.25".parse::<Range>().unwrap();
+        assert_eq!(range.duration(), Some(90.25));
+        assert!(range.contains(60.0));
+
+        let range = "npt=10-".parse::<Range>().unwrap();
+        assert_eq!(range.duration(), None);
+        assert!(range.contains(1000.0));
+        assert!(!range.contains(5.0));
+
+        let range = "npt=now-".parse::<Range>().unwrap();
+        assert_eq!(range.duration(), None);
+        assert!(!range.contains(0.0));
+    }
+
+    #[test]
+    fn test_npt_shifted_by() {
+        let range = NptRange::FromTo(NptTime::Hms(0, 1, 0, None), NptTime::Now);
+        assert_eq!(range.shifted_by(10.0), None);
+        assert_eq!(NptRange::Empty.shifted_by(10.0), None);
+
+        let range = NptRange::From(NptTime::Hms(0, 1, 0, None));
+        assert_eq!(
+            range.shifted_by(3600.5),
+            Some(NptRange::From(NptTime::Hms(1, 1, 0, Some(500_000_000))))
+        );
+
+        let range = NptRange::To(NptTime::Seconds(5, Some(250_000_000)));
+        assert_eq!(
+            range.shifted_by(-0.25),
+            Some(NptRange::To(NptTime::Seconds(5, Some(0))))
+        );
+        assert_eq!(
+            range.shifted_by(-10.0),
+            Some(NptRange::To(NptTime::Seconds(0, Some(0))))
+        );
+    }
+
+    #[test]
+    fn test_smpte_time_seconds() {
+        let time = "00:00:01:12.50".parse::<SmpteTime>().unwrap();
+        assert_eq!(time.as_seconds(25.0), 1.5);
+        assert_eq!(SmpteTime::from_seconds(1.5, 25.0), time);
+
+        let time = "01:02:03".parse::<SmpteTime>().unwrap();
+        assert_eq!(time.as_seconds(30.0), 3723.0);
+        assert_eq!(
+            SmpteTime::from_seconds(3723.0, 30.0),
+            SmpteTime {
+                frames: Some((0, None)),
+                ..time
+            }
+        );
+
+        // First frame after the first minute in drop-frame timecode is frame 2
+        let time = "00:01:00:02".parse::<SmpteTime>().unwrap();
+        assert!((time.as_seconds(29.97) - 1800.0 * 1001.0 / 30000.0).abs() < 1e-9);
+        assert_eq!(SmpteTime::from_seconds(time.as_seconds(29.97), 29.97), time);
+
+        // No frames are dropped every tenth minute
+        let time = "00:10:00:00".parse::<SmpteTime>().unwrap();
+        assert!((time.as_seconds(29.97) - 17982.0 * 1001.0 / 30000.0).abs() < 1e-9);
+        assert_eq!(SmpteTime::from_seconds(time.as_seconds(29.97), 29.97), time);
+
+        let time = "00:10:59:29".parse::<SmpteTime>().unwrap();
+        assert_eq!(SmpteTime::from_seconds(time.as_seconds(29.97), 29.97), time);
+    }
+
+    #[test]
+    fn test_utc_time_extended() {
+        let basic = "19961108T143720.25Z".parse::<UtcTime>().unwrap();
+        let extended = "1996-11-08T14:37:20.25Z".parse::<UtcTime>().unwrap();
+        assert_eq!(basic, extended);
+        assert_eq!(extended.to_string(), "19961108T143720.250000000Z");
+
+        assert!("1996-11-08T14:3720Z".parse::<UtcTime>().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_absolute_time() {
+        use chrono::TimeZone;
+        use std::convert::TryFrom;
+
+        let time = AbsoluteTime::try_from("1996-11-08T14:37:20.25Z").unwrap();
+        assert_eq!(
+            time.0,
+            chrono::Utc
+                .with_ymd_and_hms(1996, 11, 8, 14, 37, 20)
+                .unwrap()
+                + chrono::Duration::milliseconds(250)
+        );
+        assert_eq!(time.to_string(), "19961108T143720.250000000Z");
+        assert_eq!("19961108T143720.25Z".parse::<AbsoluteTime>(), Ok(time));
+
+        assert!("19961308T143720Z".parse::<AbsoluteTime>().is_err());
+    }
 }
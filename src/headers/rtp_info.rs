@@ -40,6 +40,24 @@ impl RtpInfos {
     }
 }
 
+// Converts `rtptime` to an NTP timestamp given a reference NTP/RTP timestamp pair, as in
+// RFC 3550 section 6.4.1. RTP timestamp wraparounds are handled by taking the shortest distance.
+fn ntp_from_rtptime(
+    rtptime: u32,
+    rtp_clock_rate: u32,
+    reference_ntp: u64,
+    reference_rtptime: u32,
+) -> Option<u64> {
+    if rtp_clock_rate == 0 {
+        return None;
+    }
+
+    let diff = rtptime.wrapping_sub(reference_rtptime) as i32 as i128;
+    let ntp_diff = (diff << 32) / rtp_clock_rate as i128;
+
+    Some((reference_ntp as i128 + ntp_diff) as u64)
+}
+
 pub mod v1 {
     use super::*;
 
@@ -54,6 +72,30 @@ pub mod v1 {
         pub rtptime: Option<u32>,
     }
 
+    impl RtpInfo {
+        /// Computes the NTP timestamp of the first packet after the request.
+        ///
+        /// `reference_ntp` is a 64 bit NTP timestamp and `reference_rtptime` the RTP timestamp
+        /// corresponding to it, e.g. from an RTCP sender report, and `rtp_clock_rate` is the
+        /// clock rate of the RTP stream. See
+        /// [RFC 3550 section 5.1](https://tools.ietf.org/html/rfc3550#section-5.1).
+        ///
+        /// Returns `None` if there is no `rtptime` or the clock rate is zero.
+        pub fn ntp_from_rtptime(
+            &self,
+            rtp_clock_rate: u32,
+            reference_ntp: u64,
+            reference_rtptime: u32,
+        ) -> Option<u64> {
+            super::ntp_from_rtptime(
+                self.rtptime?,
+                rtp_clock_rate,
+                reference_ntp,
+                reference_rtptime,
+            )
+        }
+    }
+
     pub(super) mod parser {
         use super::*;
 
@@ -149,6 +191,25 @@ pub mod v2 {
         pub others: BTreeMap<String, Option<String>>,
     }
 
+    impl SsrcInfo {
+        /// Computes the NTP timestamp of the first packet after the request.
+        ///
+        /// See [`v1::RtpInfo::ntp_from_rtptime`](../v1/struct.RtpInfo.html#method.ntp_from_rtptime).
+        pub fn ntp_from_rtptime(
+            &self,
+            rtp_clock_rate: u32,
+            reference_ntp: u64,
+            reference_rtptime: u32,
+        ) -> Option<u64> {
+            super::ntp_from_rtptime(
+                self.rtptime?,
+                rtp_clock_rate,
+                reference_ntp,
+                reference_rtptime,
+            )
+        }
+    }
+
     pub(super) mod parser {
         use super::*;
 
@@ -552,4 +613,34 @@ mod tests {
 
         assert_eq!(response, response2);
     }
+
+    #[test]
+    fn test_ntp_from_rtptime() {
+        let info = v2::SsrcInfo {
+            ssrc: 0x0a13c760,
+            seq: Some(45102),
+            rtptime: Some(90_000 + 45_000),
+            others: BTreeMap::new(),
+        };
+
+        // 1.5s after the reference
+        assert_eq!(
+            info.ntp_from_rtptime(90_000, 10 << 32, 0),
+            Some((11 << 32) + (1 << 31))
+        );
+        // 0.5s before the reference, across an RTP timestamp wraparound
+        assert_eq!(
+            info.ntp_from_rtptime(90_000, 10 << 32, 90_000 + 45_000 + 45_000),
+            Some((9 << 32) + (1 << 31))
+        );
+        let info = v2::SsrcInfo {
+            rtptime: Some(u32::MAX - 44_999),
+            ..info
+        };
+        assert_eq!(
+            info.ntp_from_rtptime(90_000, 10 << 32, 45_000),
+            Some(9 << 32)
+        );
+        assert_eq!(info.ntp_from_rtptime(0, 10 << 32, 0), None);
+    }
 }
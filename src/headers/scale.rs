@@ -8,6 +8,39 @@ use super::*;
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct Scale(f64);
 
+impl Scale {
+    /// Normal playback speed in forward direction.
+    pub const DEFAULT: Scale = Scale(1.0);
+
+    /// Returns `true` if playback is in forward direction.
+    pub fn is_forward(self) -> bool {
+        self.0 > 0.0
+    }
+
+    /// Returns `true` if playback is in reverse direction.
+    pub fn is_reverse(self) -> bool {
+        self.0 < 0.0
+    }
+
+    /// Returns `true` if this is normal playback speed in forward direction.
+    pub fn is_normal_speed(self) -> bool {
+        (self.0 - 1.0).abs() < f64::EPSILON
+    }
+
+    /// Returns the scale in forward direction.
+    ///
+    /// Note that this shadows `f64::abs()`, which is otherwise available via `Deref`.
+    pub fn abs(self) -> Scale {
+        Scale(self.0.abs())
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale::DEFAULT
+    }
+}
+
 impl std::ops::Deref for Scale {
     type Target = f64;
 
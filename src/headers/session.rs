@@ -4,7 +4,15 @@
 
 use super::*;
 
+use crate::Version;
+
+use std::fmt;
+
 /// `Session` header ([RFC 7826 section 18.49](https://tools.ietf.org/html/rfc7826#section-18.49)).
+///
+/// Comparing two `Session`s with `==` compares both the session identifier and the timeout. Use
+/// [`same_session`](#method.same_session) to check if two `Session`s refer to the same session
+/// regardless of their timeout.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Session(
     /// Session identifier.
@@ -14,9 +22,69 @@ pub struct Session(
 );
 
 impl Session {
+    /// Session timeout in seconds that applies if none is specified.
+    pub const DEFAULT_TIMEOUT: u64 = 60;
+
+    /// Get the session timeout, or the default timeout of 60 seconds if none is specified.
+    pub fn timeout_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.1.unwrap_or(Self::DEFAULT_TIMEOUT))
+    }
+
     pub fn with_timeout(id: String, timeout: u64) -> Self {
         Self(id, Some(timeout))
     }
+
+    /// Creates a new `Session` with the given timeout after checking the session identifier.
+    ///
+    /// Session identifiers must be between 1 and 256 characters long and only consist of
+    /// alphanumeric characters and `$`, `-`, `_`, `.` and `+`.
+    pub fn try_with_timeout(id: String, timeout: u64) -> Result<Self, SessionError> {
+        if !is_valid_session_id(&id) {
+            return Err(SessionError(id));
+        }
+
+        Ok(Self(id, Some(timeout)))
+    }
+
+    /// Creates a new `Session` with a random 22 character session identifier.
+    ///
+    /// The identifier is generated from the operating system's random number generator as
+    /// recommended by [RFC 7826 section 18.49](https://tools.ietf.org/html/rfc7826#section-18.49).
+    ///
+    /// This fails if no random numbers can be retrieved from the operating system.
+    #[cfg(feature = "getrandom")]
+    pub fn new_random() -> Result<Self, getrandom::Error> {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+        let mut id = [0u8; 22];
+        getrandom::getrandom(&mut id)?;
+
+        // 256 is a multiple of 64 so every character is equally likely
+        let id = id
+            .iter()
+            .map(|b| char::from(ALPHABET[usize::from(*b & 0x3f)]))
+            .collect::<String>();
+
+        Ok(Session(id, None))
+    }
+
+    /// Checks if both `Session`s refer to the same session.
+    ///
+    /// Only the session identifiers are compared and the timeout is ignored.
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::Session;
+    ///
+    /// let a = Session::with_timeout(String::from("abc"), 60);
+    /// let b = Session::with_timeout(String::from("abc"), 120);
+    ///
+    /// assert!(a.same_session(&b));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn same_session(&self, other: &Session) -> bool {
+        self.0 == other.0
+    }
 }
 
 impl std::ops::Deref for Session {
@@ -33,6 +101,25 @@ impl AsRef<str> for Session {
     }
 }
 
+/// Serializes the session as used in the `Session` header, i.e. `<id>` or `<id>;timeout=<n>`.
+///
+/// ```rust
+/// use rtsp_types::headers::Session;
+///
+/// assert_eq!(Session::from("abc").to_string(), "abc");
+/// assert_eq!(Session::with_timeout(String::from("abc"), 60).to_string(), "abc;timeout=60");
+/// ```
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)?;
+        if let Some(timeout) = self.1 {
+            write!(f, ";timeout={timeout}")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<'a> From<&'a str> for Session {
     fn from(v: &'a str) -> Session {
         Session(v.into(), None)
@@ -45,8 +132,68 @@ impl From<String> for Session {
     }
 }
 
+impl Session {
+    fn parse(value: &str, version: Version) -> Result<Self, HeaderParseError> {
+        let mut iter = value.split(';');
+
+        let session_id = iter.next().ok_or(HeaderParseError)?;
+        let timeout = match version {
+            Version::V2_0 => iter.find_map(|s| s.strip_prefix("timeout=")),
+            // RTSP 1.0 servers commonly add whitespace around the parameters and don't
+            // necessarily use lowercase for the parameter name.
+            Version::V1_0 => iter.find_map(|s| {
+                let (name, value) = super::parser_helpers::split_once(s, '=')?;
+                if name.trim().eq_ignore_ascii_case("timeout") {
+                    Some(value.trim())
+                } else {
+                    None
+                }
+            }),
+        };
+        let timeout = timeout
+            .map(|s| s.parse::<u64>())
+            .transpose()
+            .map_err(|_| HeaderParseError)?;
+
+        let session_id = match version {
+            Version::V2_0 => session_id,
+            Version::V1_0 => session_id.trim(),
+        };
+
+        Ok(Session(session_id.into(), timeout))
+    }
+}
+
+fn is_valid_session_id(id: &str) -> bool {
+    (1..=256).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"$-_.+".contains(&b))
+}
+
+/// Invalid session identifier.
+///
+/// Contains the session identifier that was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionError(pub String);
+
+impl std::error::Error for SessionError {}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid session identifier '{}'", self.0)
+    }
+}
+
 impl super::TypedHeader for Session {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        Self::from_headers_versioned(headers, Version::V2_0)
+    }
+
+    fn from_headers_versioned(
+        headers: impl AsRef<Headers>,
+        version: Version,
+    ) -> Result<Option<Self>, HeaderParseError> {
         let headers = headers.as_ref();
 
         let header = match headers.get(&SESSION) {
@@ -54,26 +201,12 @@ impl super::TypedHeader for Session {
             Some(header) => header,
         };
 
-        let mut iter = header.as_str().split(';');
-
-        let session_id = iter.next().ok_or(HeaderParseError)?;
-        let timeout = iter
-            .find_map(|s| s.strip_prefix("timeout="))
-            .map(|s| s.parse::<u64>())
-            .transpose()
-            .map_err(|_| HeaderParseError)?;
-
-        Ok(Some(Session(session_id.into(), timeout)))
+        Session::parse(header.as_str(), version).map(Some)
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        if let Some(timeout) = self.1 {
-            headers.insert(SESSION, format!("{};timeout={}", self.0, timeout));
-        } else {
-            headers.insert(SESSION, self.0.to_string());
-        }
+        headers.insert(SESSION, self.to_string());
     }
 }
 
@@ -162,6 +295,10 @@ mod tests {
                 Session::from_headers(test_headers).expect("strict_headers should not error");
 
             assert_eq!(from_headers_result, expected, "{header}");
+
+            let mut headers = Headers::new();
+            from_headers_result.unwrap().insert_into(&mut headers);
+            assert_eq!(headers.get(&SESSION).unwrap().as_str(), header);
         }
 
         for (header, expected) in loose_headers {
@@ -190,4 +327,53 @@ mod tests {
             assert_eq!(from_headers_result, None, "{header}:{value}");
         }
     }
+
+    #[test]
+    fn test_try_with_timeout() {
+        assert_eq!(
+            Session::try_with_timeout(String::from("abc$-_.+123"), 60),
+            Ok(Session(String::from("abc$-_.+123"), Some(60)))
+        );
+        assert_eq!(
+            Session::try_with_timeout(String::from("abc;123"), 60),
+            Err(SessionError(String::from("abc;123")))
+        );
+        assert!(Session::try_with_timeout(String::new(), 60).is_err());
+        assert!(Session::try_with_timeout("a".repeat(257), 60).is_err());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_new_random() {
+        let a = Session::new_random().unwrap();
+        let b = Session::new_random().unwrap();
+
+        assert_eq!(a.0.len(), 22);
+        assert!(is_valid_session_id(&a.0));
+        assert!(!a.same_session(&b));
+    }
+
+    #[test]
+    fn test_from_headers_rtsp_1_0() {
+        let headers = [
+            ("12345678", Some(Session("12345678".to_string(), None))),
+            (
+                "12345678; timeout = 60",
+                Some(Session("12345678".to_string(), Some(60))),
+            ),
+            (
+                "12345678 ;Timeout=60",
+                Some(Session("12345678".to_string(), Some(60))),
+            ),
+        ];
+
+        for (header, expected) in headers {
+            let mut test_headers = Headers::new();
+            test_headers.insert(SESSION, header);
+            let from_headers_result = Session::from_headers_versioned(test_headers, Version::V1_0)
+                .expect("RTSP 1.0 headers should not error");
+
+            assert_eq!(from_headers_result, expected, "{header}");
+        }
+    }
 }
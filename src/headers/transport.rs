@@ -4,9 +4,12 @@
 
 use super::*;
 
+use crate::Version;
+
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt;
+use std::net::IpAddr;
 
 /// `Transport` header ([RFC 7826 section 18.54](https://tools.ietf.org/html/rfc7826#section-18.54)).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -44,6 +47,12 @@ impl From<Vec<Transport>> for Transports {
     }
 }
 
+impl From<Transport> for Transports {
+    fn from(v: Transport) -> Self {
+        Transports(vec![v])
+    }
+}
+
 impl<'a> From<&'a [Transport]> for Transports {
     fn from(v: &'a [Transport]) -> Self {
         Transports(v.to_vec())
@@ -60,6 +69,377 @@ pub enum Transport {
     Other(OtherTransport),
 }
 
+impl Transport {
+    /// Creates a new RTP transport builder for the given RTSP version.
+    ///
+    /// By default this builds an `RTP/AVP` transport.
+    pub fn builder(version: Version) -> TransportBuilder {
+        TransportBuilder {
+            version,
+            transport: RtpTransport {
+                profile: RtpProfile::Avp,
+                lower_transport: None,
+                params: RtpTransportParameters::default(),
+            },
+        }
+    }
+
+    /// Returns `true` if this is an RTP transport over UDP.
+    ///
+    /// UDP is the default if no lower transport is given.
+    pub fn is_udp(&self) -> bool {
+        match self {
+            Transport::Rtp(rtp) => {
+                matches!(rtp.lower_transport, None | Some(RtpLowerTransport::Udp))
+            }
+            Transport::Other(_) => false,
+        }
+    }
+
+    /// Returns `true` if this is an RTP transport over TCP.
+    pub fn is_tcp(&self) -> bool {
+        match self {
+            Transport::Rtp(rtp) => rtp.lower_transport == Some(RtpLowerTransport::Tcp),
+            Transport::Other(_) => false,
+        }
+    }
+
+    /// Returns `true` if this is a unicast RTP transport.
+    pub fn is_unicast(&self) -> bool {
+        match self {
+            Transport::Rtp(rtp) => rtp.params.unicast,
+            Transport::Other(_) => false,
+        }
+    }
+
+    /// Returns `true` if this is a multicast RTP transport.
+    pub fn is_multicast(&self) -> bool {
+        match self {
+            Transport::Rtp(rtp) => rtp.params.multicast,
+            Transport::Other(_) => false,
+        }
+    }
+
+    /// Returns the multicast destination address of a multicast RTP transport.
+    ///
+    /// This is taken from the `destination` parameter for RTSP 1.0 or the first `dest_addr` for
+    /// RTSP 2.0. `None` is returned if the address is not a valid multicast address.
+    pub fn multicast_address(&self) -> Option<IpAddr> {
+        let rtp = match self {
+            Transport::Rtp(rtp) if rtp.params.multicast => rtp,
+            _ => return None,
+        };
+
+        let addr = match rtp.params.destination {
+            Some(_) => rtp.params.destination_addr()?,
+            None => parse_host_addr(rtp.params.dest_addr.first()?)?,
+        };
+
+        if addr.is_multicast() {
+            Some(addr)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the client RTP and RTCP port pair of an RTP transport.
+    ///
+    /// This is taken from the RTSP 1.0 `client_port` parameter. `None` is returned if there is no
+    /// port pair or if the RTCP port does not directly follow the RTP port.
+    pub fn client_port_range(&self) -> Option<(u16, u16)> {
+        match self {
+            Transport::Rtp(rtp) => port_pair(rtp.params.client_port?),
+            Transport::Other(_) => None,
+        }
+    }
+
+    /// Returns the server RTP and RTCP port pair of an RTP transport.
+    ///
+    /// This is taken from the RTSP 1.0 `server_port` parameter. `None` is returned if there is no
+    /// port pair or if the RTCP port does not directly follow the RTP port.
+    pub fn server_port_range(&self) -> Option<(u16, u16)> {
+        match self {
+            Transport::Rtp(rtp) => port_pair(rtp.params.server_port?),
+            Transport::Other(_) => None,
+        }
+    }
+
+    /// Returns the client RTP port of an RTP transport.
+    pub fn rtp_client_port(&self) -> Option<u16> {
+        self.client_port_range().map(|(rtp, _rtcp)| rtp)
+    }
+
+    /// Returns the client RTCP port of an RTP transport.
+    pub fn rtcp_client_port(&self) -> Option<u16> {
+        self.client_port_range().map(|(_rtp, rtcp)| rtcp)
+    }
+
+    /// Returns the RTP and RTCP interleaved channel pair of an RTP transport.
+    ///
+    /// `None` is returned if there is no channel pair or if the RTCP channel does not directly
+    /// follow the RTP channel.
+    pub fn interleaved_channels(&self) -> Option<(u8, u8)> {
+        let (rtp, rtcp) = match self {
+            Transport::Rtp(rtp) => rtp.params.interleaved?,
+            Transport::Other(_) => return None,
+        };
+
+        match rtcp {
+            Some(rtcp) if rtp.checked_add(1) == Some(rtcp) => Some((rtp, rtcp)),
+            _ => None,
+        }
+    }
+
+    /// Returns the RTP interleaved channel of an RTP transport.
+    pub fn rtp_channel(&self) -> Option<u8> {
+        self.interleaved_channels().map(|(rtp, _rtcp)| rtp)
+    }
+
+    /// Returns the RTCP interleaved channel of an RTP transport.
+    pub fn rtcp_channel(&self) -> Option<u8> {
+        self.interleaved_channels().map(|(_rtp, rtcp)| rtcp)
+    }
+
+    /// Checks if this transport carries the given SSRC.
+    ///
+    /// This is the SSRC announced in the SDP (`a=ssrc:` attribute) for the stream. See
+    /// [`check_ssrc`](#method.check_ssrc) for details about a mismatch.
+    pub fn matches_sdp_ssrc(&self, sdp_ssrc: u32) -> bool {
+        self.check_ssrc(sdp_ssrc) == SsrcDiagnostic::Match
+    }
+
+    /// Checks the `ssrc` parameter of this transport against the SSRC from the SDP.
+    pub fn check_ssrc(&self, sdp_ssrc: u32) -> SsrcDiagnostic {
+        let rtp = match self {
+            Transport::Rtp(rtp) => rtp,
+            Transport::Other(_) => return SsrcDiagnostic::NotRtp,
+        };
+
+        if rtp.params.ssrc.is_empty() {
+            SsrcDiagnostic::NoSsrc
+        } else if rtp.params.ssrc.contains(&sdp_ssrc) {
+            SsrcDiagnostic::Match
+        } else {
+            SsrcDiagnostic::Mismatch {
+                expected: sdp_ssrc,
+                transport: rtp.params.ssrc.clone(),
+            }
+        }
+    }
+
+    /// Returns the mode of an RTP transport.
+    ///
+    /// If the `mode` parameter is absent this defaults to `PLAY`. If multiple modes are given
+    /// then the first one is returned, all modes are available via the `mode` field of the
+    /// transport parameters.
+    pub fn mode(&self) -> Option<TransportMode> {
+        match self {
+            Transport::Rtp(rtp) => Some(
+                rtp.params
+                    .mode
+                    .first()
+                    .cloned()
+                    .unwrap_or(TransportMode::Play),
+            ),
+            Transport::Other(_) => None,
+        }
+    }
+
+    /// Returns the transport with the server RTP and RTCP ports set.
+    ///
+    /// This is used by servers when replying to a `SETUP` request for a UDP transport. This sets
+    /// the RTSP 1.0 `server_port` parameter. Non-RTP transports are returned unchanged.
+    pub fn with_server_port(mut self, rtp: u16, rtcp: u16) -> Transport {
+        if let Transport::Rtp(ref mut transport) = self {
+            transport.params.server_port = Some((rtp, Some(rtcp)));
+        }
+
+        self
+    }
+
+    /// Returns the transport with the RTP and RTCP interleaved channels set.
+    ///
+    /// This is used by servers when replying to a `SETUP` request for a TCP transport.
+    /// Non-RTP transports are returned unchanged.
+    pub fn with_interleaved(mut self, rtp_channel: u8, rtcp_channel: u8) -> Transport {
+        if let Transport::Rtp(ref mut transport) = self {
+            transport.params.interleaved = Some((rtp_channel, Some(rtcp_channel)));
+        }
+
+        self
+    }
+
+    /// Returns the time-to-live of a multicast RTP transport.
+    pub fn multicast_ttl(&self) -> Option<u8> {
+        match self {
+            Transport::Rtp(rtp) if rtp.params.multicast => rtp.params.ttl,
+            _ => None,
+        }
+    }
+}
+
+/// Result of checking the SSRC of a transport against the SDP.
+///
+/// See [`Transport::check_ssrc`](enum.Transport.html#method.check_ssrc).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsrcDiagnostic {
+    /// The transport carries the SSRC from the SDP.
+    Match,
+    /// The transport has no `ssrc` parameter.
+    NoSsrc,
+    /// The transport is not an RTP transport.
+    NotRtp,
+    /// The transport only carries different SSRCs.
+    Mismatch {
+        /// SSRC from the SDP.
+        expected: u32,
+        /// SSRCs of the transport.
+        transport: Vec<u32>,
+    },
+}
+
+impl fmt::Display for SsrcDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SsrcDiagnostic::Match => f.write_str("SSRC matches"),
+            SsrcDiagnostic::NoSsrc => f.write_str("Transport has no SSRC"),
+            SsrcDiagnostic::NotRtp => f.write_str("Transport is not an RTP transport"),
+            SsrcDiagnostic::Mismatch {
+                expected,
+                transport,
+            } => {
+                write!(f, "Expected SSRC {expected:08X} but transport has SSRC ")?;
+                for (idx, ssrc) in transport.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str("/")?;
+                    }
+                    write!(f, "{ssrc:08X}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn port_pair((rtp, rtcp): (u16, Option<u16>)) -> Option<(u16, u16)> {
+    match rtcp {
+        Some(rtcp) if rtp.checked_add(1) == Some(rtcp) => Some((rtp, rtcp)),
+        _ => None,
+    }
+}
+
+// Parses the address part of `host[:port]`, with IPv6 addresses optionally in brackets.
+fn parse_host_addr(s: &str) -> Option<IpAddr> {
+    if let Some(s) = s.strip_prefix('[') {
+        let (addr, _port) = s.split_once(']')?;
+        return addr.parse::<std::net::Ipv6Addr>().ok().map(IpAddr::V6);
+    }
+
+    if let Ok(addr) = s.parse::<IpAddr>() {
+        return Some(addr);
+    }
+
+    let (addr, _port) = s.rsplit_once(':')?;
+    addr.parse::<std::net::Ipv4Addr>().ok().map(IpAddr::V4)
+}
+
+/// Builder for RTP transports.
+///
+/// See [`Transport::builder`](enum.Transport.html#method.builder) for details.
+#[derive(Debug, Clone)]
+pub struct TransportBuilder {
+    version: Version,
+    transport: RtpTransport,
+}
+
+impl TransportBuilder {
+    /// Set the RTP profile.
+    pub fn profile(mut self, profile: RtpProfile) -> Self {
+        self.transport.profile = profile;
+        self
+    }
+
+    /// Set the RTP lower transport.
+    pub fn lower_transport(mut self, lower_transport: RtpLowerTransport) -> Self {
+        self.transport.lower_transport = Some(lower_transport);
+        self
+    }
+
+    /// Make this a unicast transport.
+    pub fn unicast(mut self) -> Self {
+        self.transport.params.unicast = true;
+        self.transport.params.multicast = false;
+        self
+    }
+
+    /// Make this a multicast transport with the given destination address.
+    ///
+    /// This fails if `addr` is not a multicast address, i.e. not in `224.0.0.0/4` for IPv4 or
+    /// `ff00::/8` for IPv6.
+    pub fn multicast_address(mut self, addr: IpAddr) -> Result<Self, InvalidMulticastAddress> {
+        if !addr.is_multicast() {
+            return Err(InvalidMulticastAddress(addr));
+        }
+
+        let addr_str = match addr {
+            IpAddr::V4(addr) => addr.to_string(),
+            IpAddr::V6(addr) => format!("[{addr}]"),
+        };
+
+        self.transport.params.unicast = false;
+        self.transport.params.multicast = true;
+        match self.version {
+            Version::V1_0 => self.transport.params.destination = Some(addr_str),
+            Version::V2_0 => self.transport.params.dest_addr = vec![addr_str],
+        }
+
+        Ok(self)
+    }
+
+    /// Set the multicast time-to-live.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.transport.params.ttl = Some(ttl);
+        self
+    }
+
+    /// Set the RTP and RTCP interleaved channels.
+    pub fn interleaved(mut self, rtp: u8, rtcp: u8) -> Self {
+        self.transport.params.interleaved = Some((rtp, Some(rtcp)));
+        self
+    }
+
+    /// Set the client RTP and RTCP ports.
+    pub fn client_port(mut self, rtp: u16, rtcp: u16) -> Self {
+        self.transport.params.client_port = Some((rtp, Some(rtcp)));
+        self
+    }
+
+    /// Add a transport mode.
+    pub fn mode(mut self, mode: TransportMode) -> Self {
+        self.transport.params.mode.push(mode);
+        self
+    }
+
+    /// Build the transport.
+    pub fn build(self) -> Transport {
+        Transport::Rtp(self.transport)
+    }
+}
+
+/// The address passed to
+/// [`TransportBuilder::multicast_address`](struct.TransportBuilder.html#method.multicast_address)
+/// is not a multicast address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMulticastAddress(pub IpAddr);
+
+impl std::error::Error for InvalidMulticastAddress {}
+
+impl fmt::Display for InvalidMulticastAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a multicast address", self.0)
+    }
+}
+
 /// RTP profiles.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RtpProfile {
@@ -148,8 +528,14 @@ pub struct RtpTransportParameters {
     /// Server RTP/RTCP port for unicast. RTSP 1.0 only.
     pub server_port: Option<(u16, Option<u16>)>,
     /// Destination address. RTSP 1.0 only.
+    ///
+    /// IPv6 addresses are enclosed in square brackets. See
+    /// [`destination_addr`](#method.destination_addr) for getting the parsed address.
     pub destination: Option<String>,
     /// Source address. RTSP 1.0 only.
+    ///
+    /// IPv6 addresses are enclosed in square brackets. See
+    /// [`source_addr`](#method.source_addr) for getting the parsed address.
     pub source: Option<String>,
     // TODO: setup, connection
     // TODO mikey
@@ -159,6 +545,22 @@ pub struct RtpTransportParameters {
     pub others: BTreeMap<String, Option<String>>,
 }
 
+impl RtpTransportParameters {
+    /// Returns the `destination` parameter as IP address.
+    ///
+    /// `None` is returned if there is no `destination` parameter or if it is a hostname.
+    pub fn destination_addr(&self) -> Option<IpAddr> {
+        parse_host_addr(self.destination.as_ref()?)
+    }
+
+    /// Returns the `source` parameter as IP address.
+    ///
+    /// `None` is returned if there is no `source` parameter or if it is a hostname.
+    pub fn source_addr(&self) -> Option<IpAddr> {
+        parse_host_addr(self.source.as_ref()?)
+    }
+}
+
 impl TryFrom<TransportParameters> for RtpTransportParameters {
     type Error = HeaderParseError;
 
@@ -386,6 +788,51 @@ impl fmt::Display for TransportMode {
     }
 }
 
+/// Map of interleaved channel ids in use on a connection.
+///
+/// This is used for selecting the channels of TCP/interleaved transports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ChannelMap([u64; 4]);
+
+impl ChannelMap {
+    /// Creates a new, empty channel map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the channel is in use.
+    pub fn contains(&self, channel: u8) -> bool {
+        self.0[channel as usize / 64] & (1 << (channel % 64)) != 0
+    }
+
+    /// Marks the channel as in use.
+    pub fn insert(&mut self, channel: u8) {
+        self.0[channel as usize / 64] |= 1 << (channel % 64);
+    }
+
+    /// Marks the channel as not in use anymore.
+    pub fn remove(&mut self, channel: u8) {
+        self.0[channel as usize / 64] &= !(1 << (channel % 64));
+    }
+
+    /// Selects the next pair of channels that are not in use yet for RTP and RTCP and marks them
+    /// as in use.
+    ///
+    /// The RTP channel is always even and the RTCP channel the one following it.
+    ///
+    /// Returns `None` if no such pair is available anymore.
+    pub fn allocate_pair(&mut self) -> Option<(u8, u8)> {
+        let rtp = (0..u8::MAX)
+            .step_by(2)
+            .find(|&c| !self.contains(c) && !self.contains(c + 1))?;
+
+        self.insert(rtp);
+        self.insert(rtp + 1);
+
+        Some((rtp, rtp + 1))
+    }
+}
+
 /// Other transport description.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct OtherTransport {
@@ -484,6 +931,18 @@ mod parser {
         Ok((snd, fst))
     }
 
+    // IPv6 address literal in square brackets as per RFC 3986, e.g. `[::1]`
+    fn ip_literal(input: &[u8]) -> IResult<&[u8], &[u8]> {
+        use nom::bytes::complete::take_while1;
+        use nom::combinator::recognize;
+
+        recognize(tuple((
+            tag(b"["),
+            take_while1(|c: u8| c.is_ascii_hexdigit() || c == b':' || c == b'.'),
+            tag(b"]"),
+        )))(input)
+    }
+
     fn parameter(input: &[u8]) -> IResult<&[u8], (&str, Option<&str>)> {
         if input.is_empty() {
             return Err(Err::Error(nom::error::Error::new(
@@ -497,7 +956,7 @@ mod parser {
             cond_parser(
                 tag(b"="),
                 trim(map_res(
-                    alt((quoted_string_or_address_list, rtsp_unreserved)),
+                    alt((quoted_string_or_address_list, ip_literal, rtsp_unreserved)),
                     str::from_utf8,
                 )),
             ),
@@ -560,201 +1019,228 @@ mod parser {
     }
 }
 
-impl super::TypedHeader for Transports {
-    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
-        let headers = headers.as_ref();
-
-        let header = match headers.get(&TRANSPORT) {
-            None => return Ok(None),
-            Some(header) => header,
-        };
-
-        let (_rem, transport) =
-            parser::transports(header.as_str().as_bytes()).map_err(|_| HeaderParseError)?;
-
-        Ok(Some(transport.into()))
-    }
-
-    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+/// Serializes the transport in the `Transport` header representation.
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use std::fmt::Write;
 
-        let headers = headers.as_mut();
+        match *self {
+            Transport::Rtp(ref rtp) => {
+                f.write_str("RTP/")?;
+                f.write_str(rtp.profile.as_str())?;
+                if let Some(lower_transport) = &rtp.lower_transport {
+                    f.write_char('/')?;
+                    f.write_str(lower_transport.as_str())?;
+                }
 
-        let mut transports = String::new();
-        for transport in &self.0 {
-            if !transports.is_empty() {
-                transports.push(',');
-            }
+                if rtp.params.unicast {
+                    f.write_char(';')?;
+                    f.write_str("unicast")?;
+                }
 
-            match transport {
-                Transport::Rtp(rtp) => {
-                    transports.push_str("RTP/");
-                    transports.push_str(rtp.profile.as_str());
-                    if let Some(lower_transport) = &rtp.lower_transport {
-                        transports.push('/');
-                        transports.push_str(lower_transport.as_str());
-                    }
+                if rtp.params.multicast {
+                    f.write_char(';')?;
+                    f.write_str("multicast")?;
+                }
 
-                    if rtp.params.unicast {
-                        transports.push(';');
-                        transports.push_str("unicast");
+                if let Some((channel_start, channel_end)) = &rtp.params.interleaved {
+                    f.write_char(';')?;
+                    write!(f, "interleaved={channel_start}")?;
+                    if let Some(channel_end) = channel_end {
+                        write!(f, "-{channel_end}")?;
                     }
+                }
 
-                    if rtp.params.multicast {
-                        transports.push(';');
-                        transports.push_str("multicast");
-                    }
+                if let Some(ttl) = rtp.params.ttl {
+                    f.write_char(';')?;
+                    write!(f, "ttl={ttl}")?;
+                }
 
-                    if let Some((channel_start, channel_end)) = &rtp.params.interleaved {
-                        transports.push(';');
-                        write!(&mut transports, "interleaved={channel_start}").unwrap();
-                        if let Some(channel_end) = channel_end {
-                            write!(&mut transports, "-{channel_end}").unwrap();
+                if !rtp.params.ssrc.is_empty() {
+                    f.write_char(';')?;
+
+                    f.write_str("ssrc=")?;
+                    let mut first = true;
+                    for ssrc in &rtp.params.ssrc {
+                        if first {
+                            first = false;
+                        } else {
+                            f.write_char('/')?;
                         }
-                    }
 
-                    if let Some(ttl) = rtp.params.ttl {
-                        transports.push(';');
-                        write!(&mut transports, "ttl={ttl}").unwrap();
+                        write!(f, "{ssrc:08X}")?;
                     }
+                }
 
-                    if !rtp.params.ssrc.is_empty() {
-                        transports.push(';');
-
-                        transports.push_str("ssrc=");
-                        let mut first = true;
-                        for ssrc in &rtp.params.ssrc {
-                            if first {
-                                first = false;
-                            } else {
-                                transports.push('/');
-                            }
+                if !rtp.params.dest_addr.is_empty() {
+                    f.write_char(';')?;
 
-                            write!(&mut transports, "{ssrc:08X}").unwrap();
+                    f.write_str("dest_addr=")?;
+                    let mut first = true;
+                    for addr in &rtp.params.dest_addr {
+                        if first {
+                            first = false;
+                        } else {
+                            f.write_char('/')?;
                         }
-                    }
-
-                    if !rtp.params.dest_addr.is_empty() {
-                        transports.push(';');
 
-                        transports.push_str("dest_addr=");
-                        let mut first = true;
-                        for addr in &rtp.params.dest_addr {
-                            if first {
-                                first = false;
-                            } else {
-                                transports.push('/');
-                            }
-
-                            write!(&mut transports, "\"{addr}\"").unwrap()
-                        }
+                        write!(f, "\"{addr}\"")?;
                     }
+                }
 
-                    if !rtp.params.src_addr.is_empty() {
-                        transports.push(';');
-
-                        transports.push_str("src_addr=");
-                        let mut first = true;
-                        for addr in &rtp.params.src_addr {
-                            if first {
-                                first = false;
-                            } else {
-                                transports.push('/');
-                            }
+                if !rtp.params.src_addr.is_empty() {
+                    f.write_char(';')?;
 
-                            write!(&mut transports, "\"{addr}\"").unwrap()
+                    f.write_str("src_addr=")?;
+                    let mut first = true;
+                    for addr in &rtp.params.src_addr {
+                        if first {
+                            first = false;
+                        } else {
+                            f.write_char('/')?;
                         }
-                    }
 
-                    if rtp.params.append {
-                        transports.push(';');
-                        transports.push_str("append");
+                        write!(f, "\"{addr}\"")?;
                     }
+                }
 
-                    if let Some((port_start, port_end)) = rtp.params.port {
-                        transports.push(';');
-                        write!(&mut transports, "port={port_start}").unwrap();
-                        if let Some(port_end) = port_end {
-                            write!(&mut transports, "-{port_end}").unwrap();
-                        }
-                    }
+                if rtp.params.append {
+                    f.write_char(';')?;
+                    f.write_str("append")?;
+                }
 
-                    if let Some((port_start, port_end)) = rtp.params.client_port {
-                        transports.push(';');
-                        write!(&mut transports, "client_port={port_start}").unwrap();
-                        if let Some(port_end) = port_end {
-                            write!(&mut transports, "-{port_end}").unwrap();
-                        }
+                if let Some((port_start, port_end)) = rtp.params.port {
+                    f.write_char(';')?;
+                    write!(f, "port={port_start}")?;
+                    if let Some(port_end) = port_end {
+                        write!(f, "-{port_end}")?;
                     }
+                }
 
-                    if let Some((port_start, port_end)) = rtp.params.server_port {
-                        transports.push(';');
-                        write!(&mut transports, "server_port={port_start}").unwrap();
-                        if let Some(port_end) = port_end {
-                            write!(&mut transports, "-{port_end}").unwrap();
-                        }
+                if let Some((port_start, port_end)) = rtp.params.client_port {
+                    f.write_char(';')?;
+                    write!(f, "client_port={port_start}")?;
+                    if let Some(port_end) = port_end {
+                        write!(f, "-{port_end}")?;
                     }
+                }
 
-                    if let Some(ref destination) = rtp.params.destination {
-                        transports.push(';');
-                        write!(&mut transports, "destination={destination}").unwrap();
+                if let Some((port_start, port_end)) = rtp.params.server_port {
+                    f.write_char(';')?;
+                    write!(f, "server_port={port_start}")?;
+                    if let Some(port_end) = port_end {
+                        write!(f, "-{port_end}")?;
                     }
+                }
 
-                    if let Some(ref source) = rtp.params.source {
-                        transports.push(';');
-                        write!(&mut transports, "source={source}").unwrap();
-                    }
+                if let Some(ref destination) = rtp.params.destination {
+                    f.write_char(';')?;
+                    write!(f, "destination={destination}")?;
+                }
 
-                    if !rtp.params.mode.is_empty() {
-                        transports.push(';');
+                if let Some(ref source) = rtp.params.source {
+                    f.write_char(';')?;
+                    write!(f, "source={source}")?;
+                }
 
-                        transports.push_str("mode=\"");
-                        let mut first = true;
-                        for mode in &rtp.params.mode {
-                            if first {
-                                first = false;
-                            } else {
-                                transports.push_str(", ");
-                            }
+                if !rtp.params.mode.is_empty() {
+                    f.write_char(';')?;
 
-                            transports.push_str(mode.as_str());
+                    f.write_str("mode=\"")?;
+                    let mut first = true;
+                    for mode in &rtp.params.mode {
+                        if first {
+                            first = false;
+                        } else {
+                            f.write_str(", ")?;
                         }
 
-                        transports.push('"');
+                        f.write_str(mode.as_str())?;
                     }
 
-                    if rtp.params.rtcp_mux {
-                        transports.push(';');
-                        transports.push_str("RTCP-mux");
-                    }
+                    f.write_char('"')?;
+                }
 
-                    for (name, value) in &rtp.params.others {
-                        transports.push(';');
+                if rtp.params.rtcp_mux {
+                    f.write_char(';')?;
+                    f.write_str("RTCP-mux")?;
+                }
 
-                        if let Some(value) = value {
-                            write!(&mut transports, "{name}={value}").unwrap();
-                        } else {
-                            transports.push_str(name);
-                        }
+                for (name, value) in &rtp.params.others {
+                    f.write_char(';')?;
+
+                    if let Some(value) = value {
+                        write!(f, "{name}={value}")?;
+                    } else {
+                        f.write_str(name)?;
                     }
                 }
-                Transport::Other(other) => {
-                    transports.push_str(&other.spec);
+            }
+            Transport::Other(ref other) => {
+                f.write_str(&other.spec)?;
 
-                    for (name, value) in &other.params.0 {
-                        transports.push(';');
+                for (name, value) in &other.params.0 {
+                    f.write_char(';')?;
 
-                        if let Some(value) = value {
-                            write!(&mut transports, "{name}={value}").unwrap();
-                        } else {
-                            transports.push_str(name);
-                        }
+                    if let Some(value) = value {
+                        write!(f, "{name}={value}")?;
+                    } else {
+                        f.write_str(name)?;
                     }
                 }
             }
         }
 
-        headers.insert(TRANSPORT, transports);
+        Ok(())
+    }
+}
+
+/// Serializes the transports in the `Transport` header representation.
+impl fmt::Display for Transports {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, transport) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{transport}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Transport> for HeaderValue {
+    fn from(v: Transport) -> HeaderValue {
+        HeaderValue::from(v.to_string())
+    }
+}
+
+impl From<Transports> for HeaderValue {
+    fn from(v: Transports) -> HeaderValue {
+        HeaderValue::from(v.to_string())
+    }
+}
+
+impl super::TypedHeader for Transports {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&TRANSPORT) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let (_rem, transport) =
+            parser::transports(header.as_str().as_bytes()).map_err(|_| HeaderParseError)?;
+
+        Ok(Some(transport.into()))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(TRANSPORT, self.to_string());
     }
 }
 
@@ -796,6 +1282,10 @@ mod tests {
             .empty();
 
         assert_eq!(request, request2);
+
+        assert_eq!(transports.to_string(), header);
+        assert_eq!(format!("{}", transports[0]), header);
+        assert_eq!(HeaderValue::from(transports[0].clone()), *header);
     }
 
     #[test]
@@ -952,4 +1442,241 @@ mod tests {
             .into()
         );
     }
+
+    #[test]
+    fn test_transport_lower_transport() {
+        let transport = Transport::builder(crate::Version::V2_0).unicast().build();
+        assert!(transport.is_udp());
+        assert!(!transport.is_tcp());
+        assert!(transport.is_unicast());
+        assert!(!transport.is_multicast());
+
+        let transport = Transport::builder(crate::Version::V2_0)
+            .lower_transport(RtpLowerTransport::Tcp)
+            .build();
+        assert!(!transport.is_udp());
+        assert!(transport.is_tcp());
+    }
+
+    #[test]
+    fn test_apply_transport() {
+        let transport = Transport::builder(crate::Version::V1_0)
+            .unicast()
+            .client_port(5000, 5001)
+            .build();
+
+        let mut headers = Headers::default();
+        headers
+            .apply_transport(&transport)
+            .apply_session(&crate::headers::Session::from("12345678"));
+
+        assert_eq!(
+            headers.get(&crate::headers::TRANSPORT).unwrap(),
+            "RTP/AVP;unicast;client_port=5000-5001"
+        );
+        assert_eq!(headers.get(&crate::headers::SESSION).unwrap(), "12345678");
+    }
+
+    #[test]
+    fn test_transport_mode() {
+        let transport = Transport::builder(crate::Version::V2_0).build();
+        assert_eq!(transport.mode(), Some(TransportMode::Play));
+
+        let transport = Transport::builder(crate::Version::V2_0)
+            .mode(TransportMode::Record)
+            .build();
+        assert_eq!(transport.mode(), Some(TransportMode::Record));
+
+        let transport = Transport::Other(OtherTransport {
+            spec: String::from("X-FOO"),
+            params: Default::default(),
+        });
+        assert_eq!(transport.mode(), None);
+    }
+
+    #[test]
+    fn test_transport_with_server_port() {
+        let transport = Transport::builder(crate::Version::V1_0)
+            .client_port(5000, 5001)
+            .build()
+            .with_server_port(6000, 6001);
+        assert_eq!(transport.client_port_range(), Some((5000, 5001)));
+        assert_eq!(transport.server_port_range(), Some((6000, 6001)));
+
+        let transport = Transport::builder(crate::Version::V1_0)
+            .build()
+            .with_interleaved(4, 5);
+        assert_eq!(transport.interleaved_channels(), Some((4, 5)));
+    }
+
+    #[test]
+    fn test_transport_multicast_address() {
+        let addr = "224.2.0.1".parse::<IpAddr>().unwrap();
+
+        let transport = Transport::builder(crate::Version::V1_0)
+            .multicast_address(addr)
+            .unwrap()
+            .ttl(16)
+            .build();
+        assert!(transport.is_multicast());
+        assert_eq!(transport.multicast_address(), Some(addr));
+        assert_eq!(transport.multicast_ttl(), Some(16));
+
+        let transport = Transport::builder(crate::Version::V2_0)
+            .multicast_address(addr)
+            .unwrap()
+            .build();
+        assert_eq!(transport.multicast_address(), Some(addr));
+        assert_eq!(transport.multicast_ttl(), None);
+
+        let transports = Transports::from(vec![transport]);
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&transports)
+            .empty();
+        assert_eq!(
+            request.header(&TRANSPORT).unwrap(),
+            "RTP/AVP;multicast;dest_addr=\"224.2.0.1\""
+        );
+
+        let addr = "192.0.2.1".parse::<IpAddr>().unwrap();
+        assert_eq!(
+            Transport::builder(crate::Version::V1_0)
+                .multicast_address(addr)
+                .unwrap_err(),
+            InvalidMulticastAddress(addr)
+        );
+
+        let transport = Transport::builder(crate::Version::V1_0).unicast().build();
+        assert!(!transport.is_multicast());
+        assert_eq!(transport.multicast_address(), None);
+    }
+
+    #[test]
+    fn test_transport_ipv6_destination() {
+        let header = "RTP/AVP;multicast;ttl=16;port=5000-5001;destination=[ff0e::1]";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(crate::headers::TRANSPORT, header)
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        let addr = "ff0e::1".parse::<IpAddr>().unwrap();
+        match transports[0] {
+            Transport::Rtp(ref rtp) => {
+                assert_eq!(rtp.params.destination.as_deref(), Some("[ff0e::1]"));
+                assert_eq!(rtp.params.destination_addr(), Some(addr));
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(transports[0].multicast_address(), Some(addr));
+
+        let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .typed_header(&transports)
+            .empty();
+
+        assert_eq!(request, request2);
+
+        let transport = Transport::builder(crate::Version::V1_0)
+            .multicast_address(addr)
+            .unwrap()
+            .build();
+        assert_eq!(transport.multicast_address(), Some(addr));
+    }
+
+    #[test]
+    fn test_transport_ssrc() {
+        let header = "RTP/AVP;unicast;client_port=42860-42861;ssrc=DEADBEEF";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(crate::headers::TRANSPORT, header)
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        assert!(transports[0].matches_sdp_ssrc(0xdeadbeef));
+        assert_eq!(
+            transports[0].check_ssrc(0x12345678),
+            SsrcDiagnostic::Mismatch {
+                expected: 0x12345678,
+                transport: vec![0xdeadbeef],
+            }
+        );
+        assert_eq!(
+            transports[0].check_ssrc(0x12345678).to_string(),
+            "Expected SSRC 12345678 but transport has SSRC DEADBEEF"
+        );
+
+        let transport = Transport::builder(crate::Version::V1_0).unicast().build();
+        assert_eq!(transport.check_ssrc(0x12345678), SsrcDiagnostic::NoSsrc);
+    }
+
+    #[test]
+    fn test_transport_port_range() {
+        let header = "RTP/AVP;unicast;client_port=42860-42861;server_port=6970-6972";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(crate::headers::TRANSPORT, header)
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(transports[0].client_port_range(), Some((42860, 42861)));
+        assert_eq!(transports[0].rtp_client_port(), Some(42860));
+        assert_eq!(transports[0].rtcp_client_port(), Some(42861));
+        assert_eq!(transports[0].server_port_range(), None);
+    }
+
+    #[test]
+    fn test_transport_interleaved_channels() {
+        let transport = Transport::builder(crate::Version::V1_0)
+            .lower_transport(RtpLowerTransport::Tcp)
+            .interleaved(4, 5)
+            .build();
+        assert_eq!(transport.interleaved_channels(), Some((4, 5)));
+        assert_eq!(transport.rtp_channel(), Some(4));
+        assert_eq!(transport.rtcp_channel(), Some(5));
+
+        let transport = Transport::builder(crate::Version::V1_0)
+            .lower_transport(RtpLowerTransport::Tcp)
+            .interleaved(4, 6)
+            .build();
+        assert_eq!(transport.interleaved_channels(), None);
+
+        let transport = Transport::builder(crate::Version::V1_0)
+            .interleaved(255, 0)
+            .build();
+        assert_eq!(transport.interleaved_channels(), None);
+    }
+
+    #[test]
+    fn test_channel_map_allocate_pair() {
+        let mut channels = ChannelMap::new();
+        channels.insert(0);
+
+        assert_eq!(channels.allocate_pair(), Some((2, 3)));
+        assert!(!channels.contains(1));
+        assert_eq!(channels.allocate_pair(), Some((4, 5)));
+
+        channels.remove(2);
+        channels.remove(3);
+        assert_eq!(channels.allocate_pair(), Some((2, 3)));
+
+        let mut channels = ChannelMap::new();
+        for channel in 0..=u8::MAX {
+            if channel != 253 && channel != 254 {
+                channels.insert(channel);
+            }
+        }
+        assert_eq!(channels.allocate_pair(), None);
+
+        channels.remove(255);
+        assert_eq!(channels.allocate_pair(), Some((254, 255)));
+    }
 }
@@ -7,6 +7,7 @@ use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::error;
 use std::fmt;
+use std::sync::Arc;
 
 use crate::message_ref::HeaderRef;
 
@@ -15,6 +16,9 @@ use crate::message_ref::HeaderRef;
 /// [`Request`](../struct.Request.html) and [`Response`](../struct.Response.html) implement
 /// `AsRef<Headers>` and `AsMut<Headers>, which allows functions working with headers to be
 /// implemented generically over those traits.
+// The `header_storage` benchmark in `bench/` compares this with `HashMap` and `IndexMap`. For up to
+// 30 headers the `BTreeMap` has the fastest lookups, is close to `IndexMap` for building and
+// iterating, and unlike both it iterates in name order without a separate sorting step.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Headers(pub(crate) BTreeMap<HeaderName, HeaderValue>);
 
@@ -100,11 +104,34 @@ impl Headers {
         header.append_to(self);
     }
 
+    /// Sets the `Transport` header to a single transport.
+    ///
+    /// If a `Transport` header already exists then it will be replaced. This returns `self` so
+    /// that multiple calls can be chained.
+    pub fn apply_transport(&mut self, transport: &super::Transport) -> &mut Self {
+        self.insert_typed(&super::Transports::from(transport.clone()));
+        self
+    }
+
+    /// Sets the `Session` header.
+    ///
+    /// If a `Session` header already exists then it will be replaced. This returns `self` so
+    /// that multiple calls can be chained.
+    pub fn apply_session(&mut self, session: &super::Session) -> &mut Self {
+        self.insert_typed(session);
+        self
+    }
+
     /// Removes and RTSP header if it exists.
     pub fn remove(&mut self, name: &HeaderName) {
         self.0.remove(name);
     }
 
+    /// Removes an RTSP header and returns its value if it exists.
+    pub fn take(&mut self, name: &HeaderName) -> Option<HeaderValue> {
+        self.0.remove(name)
+    }
+
     /// Gets an RTSP header value if it exists.
     pub fn get(&self, name: &HeaderName) -> Option<&HeaderValue> {
         self.0.get(name)
@@ -115,16 +142,61 @@ impl Headers {
         H::from_headers(self)
     }
 
+    /// Gets a typed RTSP header value if it exists, using the parsing rules of `version`.
+    pub fn get_typed_versioned<H: TypedHeader>(
+        &self,
+        version: crate::Version,
+    ) -> Result<Option<H>, HeaderParseError> {
+        H::from_headers_versioned(self, version)
+    }
+
     /// Gets a mutable reference to an RTSP header value if it exists.
     pub fn get_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
         self.0.get_mut(name)
     }
 
+    /// Modifies an RTSP header value with a closure if it exists.
+    ///
+    /// Returns `true` if the header exists and the closure was called.
+    ///
+    /// ```rust
+    /// let mut request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::Options,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .header(rtsp_types::headers::USER_AGENT, " Client ")
+    /// .empty();
+    ///
+    /// let headers: &mut rtsp_types::Headers = request.as_mut();
+    /// assert!(headers.update(&rtsp_types::headers::USER_AGENT, |value| {
+    ///     *value = value.as_str().trim().into();
+    /// }));
+    /// assert!(!headers.update(&rtsp_types::headers::SERVER, |_| unreachable!()));
+    ///
+    /// assert_eq!(request.header(&rtsp_types::headers::USER_AGENT).unwrap(), "Client");
+    /// ```
+    pub fn update<F: FnOnce(&mut HeaderValue)>(&mut self, name: &HeaderName, f: F) -> bool {
+        match self.0.get_mut(name) {
+            Some(value) => {
+                f(value);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Iterator over all header name and value pairs.
     pub fn iter(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
         self.0.iter()
     }
 
+    /// Iterator over all header name and mutable value pairs.
+    ///
+    /// This allows modifying the values of all headers in place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&HeaderName, &mut HeaderValue)> {
+        self.0.iter_mut()
+    }
+
     /// Iterator over all header names.
     pub fn names(&self) -> impl Iterator<Item = &HeaderName> {
         self.0.keys()
@@ -134,6 +206,190 @@ impl Headers {
     pub fn values(&self) -> impl Iterator<Item = &HeaderValue> {
         self.0.values()
     }
+
+    /// Creates new headers containing only the given headers.
+    ///
+    /// Headers that don't exist in `self` are omitted.
+    pub fn clone_subset(&self, names: &[HeaderName]) -> Headers {
+        let mut headers = Headers::new();
+        for name in names {
+            if let Some((name, value)) = self.0.get_key_value(name) {
+                headers.0.insert(name.clone(), value.clone());
+            }
+        }
+
+        headers
+    }
+
+    /// Creates a copy of the headers that is safe for logging.
+    ///
+    /// The values of headers containing credentials, i.e. `Authorization` and
+    /// `Proxy-Authorization`, are replaced by `<redacted>`.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Options,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .header(rtsp_types::headers::CSEQ, "1")
+    ///     .header(rtsp_types::headers::AUTHORIZATION, "Basic dXNlcjpwYXNz")
+    ///     .empty();
+    ///
+    /// let headers: &rtsp_types::Headers = request.as_ref();
+    /// let sanitized = headers.sanitize_for_log();
+    ///
+    /// assert_eq!(sanitized[&rtsp_types::headers::CSEQ].as_str(), "1");
+    /// assert_eq!(sanitized[&rtsp_types::headers::AUTHORIZATION].as_str(), "<redacted>");
+    /// ```
+    pub fn sanitize_for_log(&self) -> Headers {
+        const SENSITIVE_HEADERS: &[HeaderName] =
+            &[super::AUTHORIZATION, super::PROXY_AUTHORIZATION];
+
+        let mut headers = self.clone();
+        for (name, value) in headers.0.iter_mut() {
+            if SENSITIVE_HEADERS.contains(name) {
+                *value = HeaderValue::from("<redacted>");
+            }
+        }
+
+        headers
+    }
+
+    /// Checks that all mandatory headers for a request with the given method are present.
+    ///
+    /// `CSeq` is required for all requests, `Transport` for `SETUP` and `Session` for `PLAY`,
+    /// `PAUSE`, `TEARDOWN`, `GET_PARAMETER` and `SET_PARAMETER`.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::Play,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .header(rtsp_types::headers::CSEQ, "1")
+    /// .empty();
+    ///
+    /// let headers: &rtsp_types::Headers = request.as_ref();
+    /// assert_eq!(
+    ///     headers.validate_request(request.method()),
+    ///     Err(rtsp_types::headers::ValidationError {
+    ///         missing: vec![rtsp_types::headers::SESSION],
+    ///     }),
+    /// );
+    /// ```
+    pub fn validate_request(&self, method: &crate::Method) -> Result<(), ValidationError> {
+        use crate::Method;
+
+        let mut required = vec![super::CSEQ];
+        match method {
+            Method::Setup => required.push(super::TRANSPORT),
+            Method::Play
+            | Method::Pause
+            | Method::Teardown
+            | Method::GetParameter
+            | Method::SetParameter => required.push(super::SESSION),
+            _ => (),
+        }
+
+        self.validate(required)
+    }
+
+    /// Checks that all mandatory headers for a response to a request with the given method are
+    /// present.
+    ///
+    /// `CSeq` is required for all responses. For `OK` responses, `Transport` and `Session` are
+    /// required for `SETUP`, `RTP-Info` for `PLAY` and `Content-Type` and `Content-Length` for
+    /// `DESCRIBE`.
+    pub fn validate_response(
+        &self,
+        method: &crate::Method,
+        status: crate::StatusCode,
+    ) -> Result<(), ValidationError> {
+        use crate::{Method, StatusCode};
+
+        let mut required = vec![super::CSEQ];
+        if status == StatusCode::Ok {
+            match method {
+                Method::Setup => required.extend([super::TRANSPORT, super::SESSION]),
+                Method::Play => required.push(super::RTP_INFO),
+                Method::Describe => required.extend([super::CONTENT_TYPE, super::CONTENT_LENGTH]),
+                _ => (),
+            }
+        }
+
+        self.validate(required)
+    }
+
+    /// Number of bytes the headers occupy when serialized.
+    ///
+    /// This includes the `Name: Value\r\n` framing of each header and the empty line that
+    /// terminates the headers.
+    pub fn total_byte_len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(name, value)| name.as_str().len() + 2 + value.byte_len() + 2)
+            .sum::<usize>()
+            + 2
+    }
+
+    fn validate(&self, required: Vec<HeaderName>) -> Result<(), ValidationError> {
+        let missing = required
+            .into_iter()
+            .filter(|name| !self.0.contains_key(name))
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { missing })
+        }
+    }
+}
+
+/// Gets an RTSP header value.
+///
+/// This panics if the header does not exist. Use [`Headers::get`](#method.get) for fallible
+/// access.
+impl<'a> std::ops::Index<&'a HeaderName> for Headers {
+    type Output = HeaderValue;
+
+    fn index(&self, name: &'a HeaderName) -> &HeaderValue {
+        self.get(name).expect("Header not found")
+    }
+}
+
+/// Gets an RTSP header value, comparing the header name case-insensitively.
+///
+/// This panics if the header does not exist. Use [`Headers::get`](#method.get) for fallible
+/// access.
+///
+/// ```rust
+/// let request = rtsp_types::Request::builder(
+///     rtsp_types::Method::Options,
+///     rtsp_types::Version::V2_0,
+/// )
+/// .header(rtsp_types::headers::CSEQ, "1")
+/// .empty();
+///
+/// let headers: &rtsp_types::Headers = request.as_ref();
+/// assert_eq!(headers["cseq"].as_str(), "1");
+/// assert_eq!(headers[&rtsp_types::headers::CSEQ].as_str(), "1");
+/// ```
+impl<'a> std::ops::Index<&'a str> for Headers {
+    type Output = HeaderValue;
+
+    fn index(&self, name: &'a str) -> &HeaderValue {
+        self.0
+            .iter()
+            .find(|(n, _)| **n == *name)
+            .map(|(_, value)| value)
+            .expect("Header not found")
+    }
+}
+
+impl Default for Headers {
+    fn default() -> Self {
+        Headers::new()
+    }
 }
 
 impl AsRef<Headers> for Headers {
@@ -148,6 +404,89 @@ impl AsMut<Headers> for Headers {
     }
 }
 
+/// A shared, immutable collection of RTSP headers.
+///
+/// Cloning `ArcHeaders` only increments a reference count, which allows the same headers to be
+/// read from multiple places without copying them.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// let request = rtsp_types::Request::builder(
+///     rtsp_types::Method::Options,
+///     rtsp_types::Version::V2_0,
+/// )
+/// .header(rtsp_types::headers::CSEQ, "1")
+/// .empty();
+///
+/// let headers: &rtsp_types::Headers = request.as_ref();
+/// let headers = rtsp_types::headers::ArcHeaders::from(headers.clone());
+/// let shared = headers.clone();
+/// assert_eq!(
+///     shared.get(&rtsp_types::headers::CSEQ).map(|v| v.as_str()),
+///     Some("1"),
+/// );
+///
+/// // Still shared, so can't be converted back without copying
+/// let headers = rtsp_types::Headers::try_from(headers).unwrap_err();
+/// drop(shared);
+/// assert!(rtsp_types::Headers::try_from(headers).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ArcHeaders(Arc<Headers>);
+
+impl ArcHeaders {
+    /// Gets an RTSP header value if it exists.
+    pub fn get(&self, name: &HeaderName) -> Option<&HeaderValue> {
+        self.0.get(name)
+    }
+
+    /// Gets a typed RTSP header value if it exists.
+    pub fn get_typed<H: TypedHeader>(&self) -> Result<Option<H>, HeaderParseError> {
+        self.0.get_typed()
+    }
+
+    /// Iterator over all header name and value pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
+        self.0.iter()
+    }
+
+    /// Iterator over all header names.
+    pub fn names(&self) -> impl Iterator<Item = &HeaderName> {
+        self.0.names()
+    }
+
+    /// Iterator over all header values.
+    pub fn values(&self) -> impl Iterator<Item = &HeaderValue> {
+        self.0.values()
+    }
+}
+
+impl AsRef<Headers> for ArcHeaders {
+    fn as_ref(&self) -> &Headers {
+        &self.0
+    }
+}
+
+/// Freezes headers so they can be shared.
+impl From<Headers> for ArcHeaders {
+    fn from(headers: Headers) -> Self {
+        ArcHeaders(Arc::new(headers))
+    }
+}
+
+/// Converts shared headers back into mutable headers.
+///
+/// This only succeeds if there are no other references to the same headers, otherwise the
+/// shared headers are returned unchanged as error.
+impl TryFrom<ArcHeaders> for Headers {
+    type Error = ArcHeaders;
+
+    fn try_from(headers: ArcHeaders) -> Result<Self, ArcHeaders> {
+        Arc::try_unwrap(headers.0).map_err(ArcHeaders)
+    }
+}
+
 /// Representation of an RTSP header name.
 ///
 /// This ensures that the header name only contains ASCII characters and comparisons on it are
@@ -177,6 +516,103 @@ impl HeaderName {
     pub(crate) const fn from_static_str_unchecked(v: &'static str) -> HeaderName {
         Self(Cow::Borrowed(v))
     }
+
+    /// Convert a static `&str` to a header name without checking it.
+    ///
+    /// This allows defining constants for extension headers without any runtime cost:
+    ///
+    /// ```rust
+    /// // SAFETY: "X-Custom" is ASCII
+    /// const X_CUSTOM: rtsp_types::HeaderName =
+    ///     unsafe { rtsp_types::HeaderName::from_static_str_unchecked_pub("X-Custom") };
+    ///
+    /// assert_eq!(X_CUSTOM.as_str(), "X-Custom");
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `v` must only contain ASCII characters. Other code is allowed to rely on header names
+    /// being ASCII.
+    pub const unsafe fn from_static_str_unchecked_pub(v: &'static str) -> HeaderName {
+        Self::from_static_str_unchecked(v)
+    }
+
+    /// Checks if this is an extension header.
+    ///
+    /// This is the case for all headers starting with `X-` and all headers that are not
+    /// standard headers.
+    pub fn is_extension(&self) -> bool {
+        has_extension_prefix(self.as_str()) || HeaderName::lookup(self.as_str()).is_none()
+    }
+
+    /// Creates an extension header name.
+    ///
+    /// `X-` is prepended to the name unless it already starts with it.
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     rtsp_types::HeaderName::extension("Vendor-Info").unwrap().as_str(),
+    ///     "X-Vendor-Info",
+    /// );
+    /// assert_eq!(
+    ///     rtsp_types::HeaderName::extension("X-Vendor-Info").unwrap().as_str(),
+    ///     "X-Vendor-Info",
+    /// );
+    /// ```
+    pub fn extension(name: &str) -> Result<HeaderName, AsciiError> {
+        if has_extension_prefix(name) {
+            HeaderName::try_from(name)
+        } else {
+            HeaderName::try_from(format!("X-{name}"))
+        }
+    }
+
+    /// All standard header names defined by this crate.
+    ///
+    /// The header names are sorted by their lowercase name, see
+    /// [`ALL_STANDARD`](headers/static.ALL_STANDARD.html).
+    pub fn all_standard() -> &'static [HeaderName] {
+        super::ALL_STANDARD
+    }
+
+    /// Looks up the standard header with the given name.
+    ///
+    /// The lookup is case-insensitive and does not involve any heap allocations.
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     rtsp_types::HeaderName::lookup("content-type"),
+    ///     Some(&rtsp_types::headers::CONTENT_TYPE),
+    /// );
+    /// assert_eq!(rtsp_types::HeaderName::lookup("X-Custom"), None);
+    /// ```
+    pub fn lookup(s: &str) -> Option<&'static HeaderName> {
+        super::STANDARD_HEADERS
+            .binary_search_by(|(name, _)| {
+                name.bytes().cmp(s.bytes().map(|b| b.to_ascii_lowercase()))
+            })
+            .ok()
+            .map(|idx| super::STANDARD_HEADERS[idx].1)
+    }
+
+    /// Create a header name from a `&[u8]`.
+    ///
+    /// This is the same as the `TryFrom<&[u8]>` implementation.
+    pub fn from_bytes(b: &[u8]) -> Result<HeaderName, AsciiError> {
+        Self::try_from(b)
+    }
+
+    /// Create a header name from a `String`.
+    ///
+    /// This is the same as the `TryFrom<String>` implementation and does not involve an
+    /// additional heap allocation.
+    pub fn from_string(s: String) -> Result<HeaderName, AsciiError> {
+        Self::try_from(s)
+    }
+}
+
+fn has_extension_prefix(name: &str) -> bool {
+    name.len() >= 2 && name.as_bytes()[..2].eq_ignore_ascii_case(b"X-")
 }
 
 /// Create a header name from a `&[u8]`.
@@ -188,7 +624,16 @@ impl<'a> TryFrom<&'a [u8]> for HeaderName {
             return Err(AsciiError);
         }
 
-        let v = String::from_utf8(v.into()).map_err(|_| AsciiError)?;
+        let v = std::str::from_utf8(v).map_err(|_| AsciiError)?;
+
+        // Standard header names with the same case can be shared instead of allocating
+        if let Some(name) = HeaderName::lookup(v) {
+            if name.as_str() == v {
+                return Ok(name.clone());
+            }
+        }
+
+        let v = String::from(v);
 
         Ok(HeaderName(Cow::Owned(v)))
     }
@@ -219,6 +664,17 @@ impl TryFrom<String> for HeaderName {
     }
 }
 
+/// Parse a header name from a `&str`.
+///
+/// This is the same as the `TryFrom<&str>` implementation.
+impl std::str::FromStr for HeaderName {
+    type Err = AsciiError;
+
+    fn from_str(s: &str) -> Result<HeaderName, AsciiError> {
+        Self::try_from(s)
+    }
+}
+
 /// Case-insensitive comparison of header names.
 impl PartialEq for HeaderName {
     fn eq(&self, other: &Self) -> bool {
@@ -239,11 +695,8 @@ impl Ord for HeaderName {
         let s = self.0.as_bytes();
         let o = other.0.as_bytes();
 
-        let len = std::cmp::min(s.len(), o.len());
-
-        let s = &s[..len];
-        let o = &o[..len];
-
+        // Zipping stops at the end of the shorter name, if all bytes up to there are equal
+        // then the shorter name is ordered first.
         for (s, o) in Iterator::zip(s.iter(), o.iter()) {
             let mut s = *s;
             let mut o = *o;
@@ -268,7 +721,7 @@ impl std::hash::Hash for HeaderName {
         H: std::hash::Hasher,
     {
         for b in self.0.as_bytes() {
-            b.hash(h)
+            b.to_ascii_lowercase().hash(h)
         }
     }
 }
@@ -334,6 +787,80 @@ impl HeaderValue {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    /// Get the header value truncated to at most `max_chars` characters.
+    ///
+    /// If the value was truncated then `...` is appended. This is mostly useful for logging.
+    ///
+    /// ```rust
+    /// let value = rtsp_types::HeaderValue::from("Grüße aus Berlin");
+    ///
+    /// assert_eq!(value.truncated(5), "Grüße...");
+    /// assert_eq!(value.truncated(16), "Grüße aus Berlin");
+    /// ```
+    pub fn truncated(&self, max_chars: usize) -> Cow<'_, str> {
+        match self.0.char_indices().nth(max_chars) {
+            None => Cow::Borrowed(&self.0),
+            Some((idx, _)) => Cow::Owned(format!("{}...", &self.0[..idx])),
+        }
+    }
+
+    /// Compares two header values in constant time.
+    ///
+    /// The comparison time only depends on the length of the values and not on their content.
+    /// This should be used for security-sensitive comparisons, e.g. of the `response` value of
+    /// digest authentication, to prevent timing side-channels.
+    pub fn eq_constant_time(&self, other: &HeaderValue) -> bool {
+        let a = self.0.as_bytes();
+        let b = other.0.as_bytes();
+
+        if a.len() != b.len() {
+            return false;
+        }
+
+        let diff = a
+            .iter()
+            .zip(b.iter())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b));
+
+        // Prevent the compiler from short-circuiting the loop above.
+        // SAFETY: Reading from a reference to a local variable is always valid.
+        unsafe { std::ptr::read_volatile(&diff) == 0 }
+    }
+
+    /// Checks if the header value is a valid `token`.
+    ///
+    /// See [RFC 7826 section 20.1](https://tools.ietf.org/html/rfc7826#section-20.1).
+    pub fn is_token(&self) -> bool {
+        !self.0.is_empty() && self.0.bytes().all(crate::parser::is_token_char)
+    }
+
+    /// Checks if the header value is a single valid `quoted-string`, including the quotes.
+    ///
+    /// See [RFC 7826 section 20.1](https://tools.ietf.org/html/rfc7826#section-20.1).
+    pub fn is_quoted_string(&self) -> bool {
+        self.0.len() >= 2
+            && matches!(
+                super::parser_helpers::quoted_string(self.0.as_bytes()),
+                Ok((rest, _)) if rest.is_empty()
+            )
+    }
+
+    /// Number of bytes of the header value when serialized.
+    ///
+    /// This does not include the header name or any framing.
+    pub fn byte_len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Create a header value from a `&[u8]`, replacing invalid UTF-8 sequences.
+    ///
+    /// This is lossy: any invalid UTF-8 sequences are replaced with the Unicode replacement
+    /// character (U+FFFD), the same as `String::from_utf8_lossy`. Use the `TryFrom<&[u8]>`
+    /// implementation if invalid UTF-8 should be treated as an error instead.
+    pub fn from_bytes_lossy(b: &[u8]) -> HeaderValue {
+        HeaderValue(String::from_utf8_lossy(b).into_owned())
+    }
 }
 
 impl From<String> for HeaderValue {
@@ -348,6 +875,17 @@ impl<'a> From<&'a str> for HeaderValue {
     }
 }
 
+/// Parse a header value from a `&str`.
+///
+/// This never fails as any UTF-8 string is a valid header value.
+impl std::str::FromStr for HeaderValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<HeaderValue, Self::Err> {
+        Ok(HeaderValue::from(s))
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for HeaderValue {
     type Error = Utf8Error;
 
@@ -400,6 +938,17 @@ impl fmt::Display for HeaderValue {
 pub trait TypedHeader: Sized {
     /// Parses the header from headers.
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError>;
+    /// Parses the header from headers with the parsing rules of a specific RTSP version.
+    ///
+    /// Some headers are handled slightly differently between RTSP 1.0 and RTSP 2.0. By default
+    /// this is the same as [`from_headers`](#tymethod.from_headers).
+    fn from_headers_versioned(
+        headers: impl AsRef<Headers>,
+        version: crate::Version,
+    ) -> Result<Option<Self>, HeaderParseError> {
+        let _ = version;
+        Self::from_headers(headers)
+    }
     /// Inserts the header into headers.
     fn insert_into(&self, headers: impl AsMut<Headers>);
 }
@@ -445,3 +994,171 @@ impl fmt::Display for HeaderParseError {
         write!(fmt, "Error parsing error")
     }
 }
+
+/// Mandatory headers are missing from a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Names of all missing headers.
+    pub missing: Vec<HeaderName>,
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Missing mandatory headers: ")?;
+        for (idx, name) in self.missing.iter().enumerate() {
+            if idx > 0 {
+                fmt.write_str(", ")?;
+            }
+            fmt.write_str(name.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_headers() {
+        for w in super::super::STANDARD_HEADERS.windows(2) {
+            assert!(w[0].0 < w[1].0, "{} >= {}", w[0].0, w[1].0);
+        }
+
+        for (name, header) in super::super::STANDARD_HEADERS {
+            assert_eq!(header.as_str().to_ascii_lowercase(), *name);
+            assert_eq!(HeaderName::lookup(header.as_str()), Some(*header));
+        }
+
+        assert_eq!(HeaderName::all_standard(), super::super::ALL_STANDARD);
+        assert_eq!(
+            super::super::ALL_STANDARD.len(),
+            super::super::STANDARD_HEADERS.len()
+        );
+        for (header, (_, expected)) in HeaderName::all_standard()
+            .iter()
+            .zip(super::super::STANDARD_HEADERS)
+        {
+            assert_eq!(header, *expected);
+        }
+
+        let name = HeaderName::try_from("CSeq").unwrap();
+        assert!(matches!(name.0, Cow::Borrowed(_)));
+        let name = HeaderName::try_from("cseq").unwrap();
+        assert!(matches!(name.0, Cow::Owned(_)));
+        assert_eq!(name.as_str(), "cseq");
+
+        assert_eq!("CSeq".parse::<HeaderName>(), Ok(super::super::CSEQ));
+        assert_eq!("Ünicode".parse::<HeaderName>(), Err(AsciiError));
+    }
+
+    #[test]
+    fn test_header_name_cmp() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let short = HeaderName::try_from("Content").unwrap();
+        let long = HeaderName::try_from("content-type").unwrap();
+        assert_eq!(short.cmp(&long), std::cmp::Ordering::Less);
+        assert_eq!(long.cmp(&short), std::cmp::Ordering::Greater);
+        assert_eq!(
+            long.cmp(&super::super::CONTENT_TYPE),
+            std::cmp::Ordering::Equal
+        );
+
+        let mut headers = Headers::new();
+        headers.insert(short, "a");
+        headers.insert(long, "b");
+        assert_eq!(headers.iter().count(), 2);
+
+        let hash = |name: &HeaderName| {
+            let mut h = DefaultHasher::new();
+            name.hash(&mut h);
+            h.finish()
+        };
+        assert_eq!(
+            hash(&HeaderName::try_from("cseq").unwrap()),
+            hash(&super::super::CSEQ)
+        );
+    }
+
+    #[test]
+    fn test_total_byte_len() {
+        let mut headers = Headers::new();
+        assert_eq!(headers.total_byte_len(), 2);
+
+        headers.insert(super::super::CSEQ, "1");
+        headers.insert(super::super::SESSION, "12345678");
+        assert_eq!(
+            headers.total_byte_len(),
+            "CSeq: 1\r\nSession: 12345678\r\n\r\n".len()
+        );
+    }
+
+    #[test]
+    fn test_clone_subset() {
+        let mut headers = Headers::new();
+        headers.insert(super::super::CSEQ, "1");
+        headers.insert(super::super::SESSION, "12345678");
+        headers.insert(super::super::CONTENT_TYPE, "text/parameters");
+
+        let subset = headers.clone_subset(&[super::super::SESSION, super::super::VIA]);
+        assert_eq!(subset.iter().count(), 1);
+        assert_eq!(subset.get(&super::super::SESSION).unwrap(), "12345678");
+    }
+
+    #[test]
+    fn test_header_value_token() {
+        assert!(HeaderValue::from("application").is_token());
+        assert!(!HeaderValue::from("").is_token());
+        assert!(!HeaderValue::from("a b").is_token());
+        assert!(!HeaderValue::from("\"abc\"").is_token());
+
+        assert!(HeaderValue::from("\"a b\"").is_quoted_string());
+        assert!(HeaderValue::from("\"a \\\" b\"").is_quoted_string());
+        assert!(!HeaderValue::from("\"").is_quoted_string());
+        assert!(!HeaderValue::from("\"a\" b").is_quoted_string());
+        assert!(!HeaderValue::from("abc").is_quoted_string());
+    }
+
+    #[test]
+    fn test_eq_constant_time() {
+        let a = HeaderValue::from("6629fae49393a05397450978507c4ef1");
+        assert!(a.eq_constant_time(&HeaderValue::from("6629fae49393a05397450978507c4ef1")));
+        assert!(!a.eq_constant_time(&HeaderValue::from("6629fae49393a05397450978507c4ef2")));
+        assert!(!a.eq_constant_time(&HeaderValue::from("6629fae49393a05397450978507c4ef")));
+    }
+
+    #[test]
+    fn test_validate_response() {
+        use crate::{Method, StatusCode};
+
+        let mut headers = Headers::new();
+        headers.insert(super::super::CSEQ, "1");
+        headers.insert(super::super::SESSION, "12345678");
+
+        assert_eq!(
+            headers.validate_response(&Method::Setup, StatusCode::Ok),
+            Err(ValidationError {
+                missing: vec![super::super::TRANSPORT],
+            })
+        );
+        assert_eq!(
+            headers.validate_response(&Method::Setup, StatusCode::SessionNotFound),
+            Ok(())
+        );
+        assert_eq!(
+            headers.validate_response(&Method::Describe, StatusCode::Ok),
+            Err(ValidationError {
+                missing: vec![super::super::CONTENT_TYPE, super::super::CONTENT_LENGTH],
+            })
+        );
+        assert_eq!(
+            headers.validate_response(&Method::Options, StatusCode::Ok),
+            Ok(())
+        );
+    }
+}
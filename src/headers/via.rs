@@ -0,0 +1,195 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use crate::Version;
+
+use std::fmt;
+
+/// `Via` header ([RFC 7826 section 18.57](https://tools.ietf.org/html/rfc7826#section-18.57)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Via(Vec<ViaEntry>);
+
+/// Single entry of the `Via` header.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViaEntry {
+    /// Protocol name, e.g. `RTSP`.
+    pub protocol_name: String,
+    /// Protocol version, e.g. `2.0`.
+    pub protocol_version: String,
+    /// Transport protocol, e.g. `TCP`.
+    pub transport: String,
+    /// Host and optional port of the proxy.
+    pub sent_by: String,
+    /// Via parameters.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl ViaEntry {
+    /// Creates a new `Via` entry for the given RTSP version, transport protocol and host.
+    pub fn new(version: Version, transport: &str, sent_by: &str) -> Self {
+        ViaEntry {
+            protocol_name: String::from("RTSP"),
+            protocol_version: String::from(match version {
+                Version::V1_0 => "1.0",
+                Version::V2_0 => "2.0",
+            }),
+            transport: String::from(transport),
+            sent_by: String::from(sent_by),
+            params: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ViaEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{} {}",
+            self.protocol_name, self.protocol_version, self.transport, self.sent_by
+        )?;
+
+        for param in &self.params {
+            if let Some(ref value) = param.1 {
+                write!(f, ";{}={}", param.0, value)?;
+            } else {
+                write!(f, ";{}", param.0)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for ViaEntry {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        use super::parser_helpers::split_once;
+
+        let mut iter = s.trim().split(';');
+        let (protocol, sent_by) =
+            split_once(iter.next().ok_or(HeaderParseError)?.trim(), ' ').ok_or(HeaderParseError)?;
+
+        let mut protocol = protocol.split('/').map(str::trim);
+        let protocol_name = protocol.next().ok_or(HeaderParseError)?;
+        let protocol_version = protocol.next().ok_or(HeaderParseError)?;
+        let transport = protocol.next().ok_or(HeaderParseError)?;
+        if protocol.next().is_some() {
+            return Err(HeaderParseError);
+        }
+
+        let sent_by = sent_by.trim();
+        if sent_by.is_empty() {
+            return Err(HeaderParseError);
+        }
+
+        let mut params = Vec::new();
+        for param in iter {
+            let param = param.trim();
+            if let Some((param, value)) = split_once(param, '=') {
+                params.push((String::from(param), Some(String::from(value))));
+            } else {
+                params.push((String::from(param), None));
+            }
+        }
+
+        Ok(ViaEntry {
+            protocol_name: String::from(protocol_name),
+            protocol_version: String::from(protocol_version),
+            transport: String::from(transport),
+            sent_by: String::from(sent_by),
+            params,
+        })
+    }
+}
+
+impl std::ops::Deref for Via {
+    type Target = Vec<ViaEntry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Via {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<ViaEntry>> for Via {
+    fn as_ref(&self) -> &Vec<ViaEntry> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<ViaEntry>> for Via {
+    fn as_mut(&mut self) -> &mut Vec<ViaEntry> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<ViaEntry>> for Via {
+    fn from(v: Vec<ViaEntry>) -> Self {
+        Via(v)
+    }
+}
+
+impl<'a> From<&'a [ViaEntry]> for Via {
+    fn from(v: &'a [ViaEntry]) -> Self {
+        Via(v.to_vec())
+    }
+}
+
+impl Via {
+    fn to_header_value(&self) -> String {
+        use std::fmt::Write;
+
+        let mut entries = String::new();
+        for entry in &self.0 {
+            if !entries.is_empty() {
+                entries.push_str(", ");
+            }
+
+            write!(&mut entries, "{entry}").unwrap();
+        }
+
+        entries
+    }
+}
+
+impl super::TypedHeader for Via {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&VIA) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let entries = header
+            .as_str()
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(Via(entries)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(VIA, self.to_header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for Via {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.append(VIA, self.to_header_value());
+    }
+}
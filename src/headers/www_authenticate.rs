@@ -0,0 +1,312 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::fmt;
+
+/// `WWW-Authenticate` header ([RFC 7826 section 18.58](https://tools.ietf.org/html/rfc7826#section-18.58)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WwwAuthenticate(Vec<Challenge>);
+
+/// Single authentication challenge of the `WWW-Authenticate` header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Challenge {
+    /// Authentication scheme, e.g. `Digest`.
+    pub scheme: String,
+    /// Authentication parameters with unquoted values.
+    pub params: Vec<(String, String)>,
+}
+
+impl Challenge {
+    /// Creates a new challenge for the given authentication scheme without parameters.
+    pub fn new(scheme: &str) -> Self {
+        Challenge {
+            scheme: String::from(scheme),
+            params: Vec::new(),
+        }
+    }
+
+    /// Get the value of the parameter `name`.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Generates a new `Digest` challenge for the given realm.
+    ///
+    /// The challenge contains a base64 encoded nonce with 128 random bits from the operating
+    /// system's random number generator and `qop="auth"`.
+    ///
+    /// This fails if no random numbers can be retrieved from the operating system.
+    ///
+    /// ```rust
+    /// let challenge = rtsp_types::headers::Challenge::generate("Streaming Server").unwrap();
+    ///
+    /// assert_eq!(challenge.scheme, "Digest");
+    /// assert_eq!(challenge.param("realm"), Some("Streaming Server"));
+    /// assert_eq!(challenge.param("nonce").unwrap().len(), 24);
+    /// assert_eq!(challenge.param("qop"), Some("auth"));
+    /// ```
+    #[cfg(feature = "getrandom")]
+    pub fn generate(realm: &str) -> Result<Self, getrandom::Error> {
+        let mut nonce = [0u8; 16];
+        getrandom::getrandom(&mut nonce)?;
+
+        Ok(Challenge {
+            scheme: String::from("Digest"),
+            params: vec![
+                (String::from("realm"), String::from(realm)),
+                (String::from("nonce"), crate::base64::encode(&nonce)),
+                (String::from("qop"), String::from("auth")),
+            ],
+        })
+    }
+}
+
+impl fmt::Display for Challenge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.scheme)?;
+
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            f.write_str(if i == 0 { " " } else { ", " })?;
+
+            // These are tokens in the Digest grammar, everything else is a quoted string
+            if name.eq_ignore_ascii_case("algorithm") || name.eq_ignore_ascii_case("stale") {
+                write!(f, "{name}={value}")?;
+            } else {
+                write!(f, "{name}=\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                f.write_str("\"")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits at commas that are not inside a quoted string.
+fn split_unquoted(s: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                items.push(&s[start..idx]);
+                start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+    items.push(&s[start..]);
+
+    items
+}
+
+fn parse_param(param: &str) -> Result<(String, String), HeaderParseError> {
+    use super::parser_helpers::split_once;
+
+    let (name, value) = split_once(param, '=').ok_or(HeaderParseError)?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() {
+        return Err(HeaderParseError);
+    }
+
+    let value = match value.strip_prefix('"') {
+        None => String::from(value),
+        Some(quoted) => {
+            let quoted = quoted.strip_suffix('"').ok_or(HeaderParseError)?;
+
+            let mut value = String::with_capacity(quoted.len());
+            let mut chars = quoted.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    value.push(chars.next().ok_or(HeaderParseError)?);
+                } else {
+                    value.push(c);
+                }
+            }
+
+            value
+        }
+    };
+
+    Ok((String::from(name), value))
+}
+
+impl std::str::FromStr for WwwAuthenticate {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        use super::parser_helpers::split_once;
+
+        let mut challenges = Vec::<Challenge>::new();
+
+        for item in split_unquoted(s) {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+
+            // A new challenge starts with the scheme followed by a space, parameters start
+            // directly with the parameter name followed by a `=`.
+            let starts_challenge = match item.find('=') {
+                None => true,
+                Some(eq) => item[..eq].trim_end().contains(' '),
+            };
+
+            if starts_challenge {
+                let (scheme, param) = split_once(item, ' ').unwrap_or((item, ""));
+                let mut challenge = Challenge::new(scheme);
+
+                let param = param.trim();
+                if !param.is_empty() {
+                    challenge.params.push(parse_param(param)?);
+                }
+
+                challenges.push(challenge);
+            } else {
+                challenges
+                    .last_mut()
+                    .ok_or(HeaderParseError)?
+                    .params
+                    .push(parse_param(item)?);
+            }
+        }
+
+        Ok(WwwAuthenticate(challenges))
+    }
+}
+
+impl std::ops::Deref for WwwAuthenticate {
+    type Target = Vec<Challenge>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for WwwAuthenticate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<Challenge>> for WwwAuthenticate {
+    fn as_ref(&self) -> &Vec<Challenge> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<Challenge>> for WwwAuthenticate {
+    fn as_mut(&mut self) -> &mut Vec<Challenge> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Challenge>> for WwwAuthenticate {
+    fn from(v: Vec<Challenge>) -> Self {
+        WwwAuthenticate(v)
+    }
+}
+
+impl<'a> From<&'a [Challenge]> for WwwAuthenticate {
+    fn from(v: &'a [Challenge]) -> Self {
+        WwwAuthenticate(v.to_vec())
+    }
+}
+
+impl WwwAuthenticate {
+    fn to_header_value(&self) -> String {
+        use std::fmt::Write;
+
+        let mut challenges = String::new();
+        for challenge in &self.0 {
+            if !challenges.is_empty() {
+                challenges.push_str(", ");
+            }
+
+            write!(&mut challenges, "{challenge}").unwrap();
+        }
+
+        challenges
+    }
+}
+
+impl super::TypedHeader for WwwAuthenticate {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&WWW_AUTHENTICATE) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        header.as_str().parse().map(Some)
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(WWW_AUTHENTICATE, self.to_header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for WwwAuthenticate {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.append(WWW_AUTHENTICATE, self.to_header_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_www_authenticate() {
+        let header = "Digest realm=\"Streaming \\\"Server\\\"\", nonce=\"abc, def\", \
+                      algorithm=MD5, Basic realm=\"Streaming Server\"";
+
+        let mut headers = Headers::new();
+        headers.insert(WWW_AUTHENTICATE, header);
+
+        let www_authenticate = headers.get_typed::<WwwAuthenticate>().unwrap().unwrap();
+        assert_eq!(
+            www_authenticate,
+            WwwAuthenticate(vec![
+                Challenge {
+                    scheme: String::from("Digest"),
+                    params: vec![
+                        (String::from("realm"), String::from("Streaming \"Server\"")),
+                        (String::from("nonce"), String::from("abc, def")),
+                        (String::from("algorithm"), String::from("MD5")),
+                    ],
+                },
+                Challenge {
+                    scheme: String::from("Basic"),
+                    params: vec![(String::from("realm"), String::from("Streaming Server"))],
+                },
+            ])
+        );
+
+        let mut new_headers = Headers::new();
+        new_headers.insert_typed(&www_authenticate);
+        assert_eq!(new_headers.get(&WWW_AUTHENTICATE).unwrap(), header);
+    }
+}
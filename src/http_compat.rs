@@ -0,0 +1,466 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Error during conversion between RTSP messages and `http` crate messages.
+///
+/// Both RTSP versions use HTTP/1.1 style text framing, so RTSP messages are converted to `HTTP/1.1`
+/// messages and the RTSP [`Version`](enum.Version.html) is stored in the extensions of the HTTP
+/// message. In the other direction only `HTTP/1.1` messages that carry the RTSP version in their
+/// extensions are accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpConversionError {
+    /// The method is not valid in the target protocol.
+    InvalidMethod(String),
+    /// The request URI is not valid in the target protocol.
+    InvalidUri(String),
+    /// The protocol version has no equivalent in the target protocol.
+    UnsupportedVersion(String),
+    /// The status code is not valid in the target protocol.
+    InvalidStatusCode(u16),
+    /// The header with the given name is not valid in the target protocol.
+    InvalidHeader(String),
+    /// The HTTP request is not a `POST` request with `application/x-rtsp-tunnelled` content.
+    NotTunnelled,
+    /// The body of the HTTP request is not a base64 encoded RTSP request.
+    InvalidTunnelBody,
+}
+
+impl std::error::Error for HttpConversionError {}
+
+impl fmt::Display for HttpConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            HttpConversionError::InvalidMethod(ref method) => {
+                write!(f, "Invalid method '{method}'")
+            }
+            HttpConversionError::InvalidUri(ref uri) => write!(f, "Invalid request URI '{uri}'"),
+            HttpConversionError::UnsupportedVersion(ref version) => {
+                write!(f, "Unsupported version {version}")
+            }
+            HttpConversionError::InvalidStatusCode(status) => {
+                write!(f, "Invalid status code {status}")
+            }
+            HttpConversionError::InvalidHeader(ref name) => {
+                write!(f, "Invalid header '{name}'")
+            }
+            HttpConversionError::NotTunnelled => write!(f, "Not a tunnelled RTSP request"),
+            HttpConversionError::InvalidTunnelBody => {
+                write!(f, "Invalid tunnelled RTSP request body")
+            }
+        }
+    }
+}
+
+fn version_from_http(
+    version: http::Version,
+    extensions: &http::Extensions,
+) -> Result<Version, HttpConversionError> {
+    match (version, extensions.get::<Version>()) {
+        (http::Version::HTTP_11, Some(version)) => Ok(*version),
+        (http::Version::HTTP_11, None) => Err(HttpConversionError::UnsupportedVersion(
+            String::from("HTTP/1.1 without RTSP version"),
+        )),
+        (version, _) => Err(HttpConversionError::UnsupportedVersion(format!(
+            "{version:?}"
+        ))),
+    }
+}
+
+fn headers_to_http(headers: &Headers) -> Result<http::HeaderMap, HttpConversionError> {
+    let mut map = http::HeaderMap::new();
+
+    for (name, value) in headers.iter() {
+        let invalid = || HttpConversionError::InvalidHeader(name.to_string());
+
+        let name = http::HeaderName::from_bytes(name.as_str().as_bytes()).map_err(|_| invalid())?;
+        let value = http::HeaderValue::from_str(value.as_str()).map_err(|_| invalid())?;
+        map.append(name, value);
+    }
+
+    Ok(map)
+}
+
+fn headers_from_http(map: &http::HeaderMap) -> Result<Headers, HttpConversionError> {
+    let mut headers = Headers::new();
+
+    for (name, value) in map.iter() {
+        let invalid = || HttpConversionError::InvalidHeader(name.to_string());
+
+        let value = value.to_str().map_err(|_| invalid())?;
+        // The http crate lowercases all header names, use the canonical spelling for standard
+        // headers again
+        let name = match HeaderName::lookup(name.as_str()) {
+            Some(name) => name.clone(),
+            None => HeaderName::from_string(name.as_str().to_owned()).map_err(|_| invalid())?,
+        };
+        headers.append(name, value);
+    }
+
+    Ok(headers)
+}
+
+/// Converts an RTSP request into an HTTP request.
+///
+/// This fails if the method is not a valid HTTP method token.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// let request = rtsp_types::Request::builder(rtsp_types::Method::Options, rtsp_types::Version::V2_0)
+///     .header(rtsp_types::headers::CSEQ, "1")
+///     .empty();
+///
+/// let request = http::Request::try_from(request).expect("Failed to convert request");
+/// assert_eq!(request.method().as_str(), "OPTIONS");
+/// assert_eq!(request.uri(), "*");
+/// assert_eq!(request.version(), http::Version::HTTP_11);
+/// assert_eq!(
+///     request.extensions().get::<rtsp_types::Version>(),
+///     Some(&rtsp_types::Version::V2_0),
+/// );
+/// assert_eq!(request.headers()["cseq"], "1");
+/// ```
+impl<Body> TryFrom<Request<Body>> for http::Request<Body> {
+    type Error = HttpConversionError;
+
+    fn try_from(request: Request<Body>) -> Result<Self, Self::Error> {
+        let method_str = <&str>::from(&request.method);
+        let method = http::Method::from_bytes(method_str.as_bytes())
+            .map_err(|_| HttpConversionError::InvalidMethod(method_str.to_owned()))?;
+
+        let uri = match request.request_uri {
+            None => http::Uri::from_static("*"),
+            Some(ref url) => http::Uri::try_from(url.as_str())
+                .map_err(|_| HttpConversionError::InvalidUri(url.to_string()))?,
+        };
+
+        let headers = headers_to_http(&request.headers)?;
+
+        let mut http_request = http::Request::new(request.body);
+        *http_request.method_mut() = method;
+        *http_request.uri_mut() = uri;
+        *http_request.version_mut() = http::Version::HTTP_11;
+        *http_request.headers_mut() = headers;
+        http_request.extensions_mut().insert(request.version);
+
+        Ok(http_request)
+    }
+}
+
+/// Converts an HTTP request into an RTSP request.
+///
+/// This fails if the method is not a valid RTSP method token, the URI is neither `*` nor an
+/// absolute URI or the request carries no RTSP version, see
+/// [`HttpConversionError`](enum.HttpConversionError.html).
+impl<Body> TryFrom<http::Request<Body>> for Request<Body> {
+    type Error = HttpConversionError;
+
+    fn try_from(request: http::Request<Body>) -> Result<Self, Self::Error> {
+        let (parts, body) = request.into_parts();
+
+        let method_str = parts.method.as_str();
+        if method_str.is_empty() || !method_str.bytes().all(parser::is_token_char) {
+            return Err(HttpConversionError::InvalidMethod(method_str.to_owned()));
+        }
+
+        let request_uri = if parts.uri == "*" {
+            None
+        } else {
+            let uri = parts.uri.to_string();
+            Some(Url::parse(&uri).map_err(|_| HttpConversionError::InvalidUri(uri))?)
+        };
+
+        Ok(Request {
+            method: Method::from(method_str),
+            request_uri,
+            version: version_from_http(parts.version, &parts.extensions)?,
+            headers: headers_from_http(&parts.headers)?,
+            body,
+            wire: WireCache::default(),
+        })
+    }
+}
+
+/// Converts an RTSP response into an HTTP response.
+///
+/// This fails if the status code is outside the range allowed by HTTP. The reason phrase is
+/// dropped.
+impl<Body> TryFrom<Response<Body>> for http::Response<Body> {
+    type Error = HttpConversionError;
+
+    fn try_from(response: Response<Body>) -> Result<Self, Self::Error> {
+        let status = u16::from(response.status);
+        let status = http::StatusCode::from_u16(status)
+            .map_err(|_| HttpConversionError::InvalidStatusCode(status))?;
+
+        let headers = headers_to_http(&response.headers)?;
+
+        let mut http_response = http::Response::new(response.body);
+        *http_response.status_mut() = status;
+        *http_response.version_mut() = http::Version::HTTP_11;
+        *http_response.headers_mut() = headers;
+        http_response.extensions_mut().insert(response.version);
+
+        Ok(http_response)
+    }
+}
+
+/// Converts an HTTP response into an RTSP response.
+///
+/// The reason phrase is set to the default one for the status code. This fails if the response
+/// carries no RTSP version, see [`HttpConversionError`](enum.HttpConversionError.html).
+impl<Body> TryFrom<http::Response<Body>> for Response<Body> {
+    type Error = HttpConversionError;
+
+    fn try_from(response: http::Response<Body>) -> Result<Self, Self::Error> {
+        let (parts, body) = response.into_parts();
+
+        let status = StatusCode::from(parts.status.as_u16());
+
+        Ok(Response {
+            version: version_from_http(parts.version, &parts.extensions)?,
+            status,
+            reason_phrase: status.to_string(),
+            headers: headers_from_http(&parts.headers)?,
+            body,
+            wire: WireCache::default(),
+        })
+    }
+}
+
+/// `Content-Type` of HTTP requests carrying tunnelled RTSP requests.
+pub const RTSP_TUNNELLED_CONTENT_TYPE: &str = "application/x-rtsp-tunnelled";
+
+impl<Body: AsRef<[u8]>> Request<Body> {
+    /// Encodes the request as an HTTP `POST` request for RTSP-over-HTTP tunnelling.
+    ///
+    /// The serialized request is base64 encoded into the body and the `Content-Type` is set to
+    /// `application/x-rtsp-tunnelled`. The HTTP request URI is the path and query of the request
+    /// URI, or `/` for `*`.
+    ///
+    /// The `x-sessioncookie` header that links the `POST` connection with the corresponding `GET`
+    /// connection has to be added by the caller.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(rtsp_types::Method::Options, rtsp_types::Version::V1_0)
+    ///     .request_uri(rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"))
+    ///     .header(rtsp_types::headers::CSEQ, "1")
+    ///     .empty();
+    ///
+    /// let http_request = request.to_http_tunnel().expect("Failed to encode request");
+    /// assert_eq!(http_request.method(), http::Method::POST);
+    /// assert_eq!(http_request.uri(), "/test");
+    ///
+    /// let decoded = rtsp_types::Request::from_http_tunnel(&http_request)
+    ///     .expect("Failed to decode request");
+    /// assert_eq!(decoded.method(), rtsp_types::Method::Options);
+    /// assert_eq!(decoded.request_uri(), request.request_uri());
+    /// ```
+    pub fn to_http_tunnel(&self) -> Result<http::Request<Vec<u8>>, HttpConversionError> {
+        let mut data = Vec::with_capacity(self.byte_len());
+        self.write(&mut data)
+            .expect("Serializing to a Vec can't fail");
+        let body = base64::encode(&data).into_bytes();
+
+        let uri = match self.request_uri {
+            Some(ref url) if !url.path().is_empty() => {
+                &url[url::Position::BeforePath..url::Position::AfterQuery]
+            }
+            _ => "/",
+        };
+
+        http::Request::post(uri)
+            .version(http::Version::HTTP_10)
+            .header(http::header::CONTENT_TYPE, RTSP_TUNNELLED_CONTENT_TYPE)
+            .header(http::header::CONTENT_LENGTH, body.len())
+            .header(http::header::PRAGMA, "no-cache")
+            .header(http::header::CACHE_CONTROL, "no-cache")
+            .body(body)
+            .map_err(|_| HttpConversionError::InvalidUri(String::from(uri)))
+    }
+}
+
+impl Request<Vec<u8>> {
+    /// Decodes an RTSP request from an HTTP `POST` request used for RTSP-over-HTTP tunnelling.
+    ///
+    /// The HTTP request must have the `application/x-rtsp-tunnelled` `Content-Type` and its body
+    /// must contain exactly one base64 encoded RTSP request. Whitespace in the body is ignored.
+    ///
+    /// See [`to_http_tunnel`](#method.to_http_tunnel) for the reverse direction.
+    pub fn from_http_tunnel<B: AsRef<[u8]>>(
+        request: &http::Request<B>,
+    ) -> Result<Self, HttpConversionError> {
+        if request.method() != http::Method::POST {
+            return Err(HttpConversionError::NotTunnelled);
+        }
+
+        let content_type = request
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(';').next().unwrap_or(value).trim());
+        match content_type {
+            Some(content_type)
+                if content_type.eq_ignore_ascii_case(RTSP_TUNNELLED_CONTENT_TYPE) => {}
+            _ => return Err(HttpConversionError::NotTunnelled),
+        }
+
+        let data = base64::decode(request.body().as_ref())
+            .ok_or(HttpConversionError::InvalidTunnelBody)?;
+
+        Request::try_from(&data[..]).map_err(|_| HttpConversionError::InvalidTunnelBody)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_roundtrip() {
+        let request = Request::builder(Method::Describe, Version::V2_0)
+            .request_uri(Url::parse("rtsp://example.com/test?foo=bar").unwrap())
+            .header(headers::CSEQ, "2")
+            .header(headers::ACCEPT, "application/sdp")
+            .build(Vec::from(&b"body"[..]));
+
+        let http_request = http::Request::try_from(request.clone()).unwrap();
+        assert_eq!(http_request.method().as_str(), "DESCRIBE");
+        assert_eq!(http_request.uri(), "rtsp://example.com/test?foo=bar");
+        assert_eq!(http_request.version(), http::Version::HTTP_11);
+        assert_eq!(http_request.extensions().get(), Some(&Version::V2_0));
+        assert_eq!(http_request.headers()["content-length"], "4");
+
+        let converted = Request::try_from(http_request).unwrap();
+        assert_eq!(converted, request);
+        assert_eq!(
+            converted
+                .header_names()
+                .map(HeaderName::as_str)
+                .collect::<Vec<_>>(),
+            ["Accept", "Content-Length", "CSeq"]
+        );
+
+        let http_request = http::Request::get("rtsp://example.com/test")
+            .header("x-custom", "1")
+            .extension(Version::V1_0)
+            .body(Empty)
+            .unwrap();
+        let converted = Request::try_from(http_request).unwrap();
+        assert_eq!(converted.version(), Version::V1_0);
+        assert_eq!(
+            converted.header_names().next().unwrap().as_str(),
+            "x-custom"
+        );
+
+        let http_request = http::Request::get("rtsp://example.com/test")
+            .body(Empty)
+            .unwrap();
+        assert!(matches!(
+            Request::try_from(http_request),
+            Err(HttpConversionError::UnsupportedVersion(_))
+        ));
+
+        let http_request = http::Request::get("/relative").body(Empty).unwrap();
+        assert_eq!(
+            Request::try_from(http_request),
+            Err(HttpConversionError::InvalidUri(String::from("/relative")))
+        );
+
+        let http_request = http::Request::get("rtsp://example.com/test")
+            .version(http::Version::HTTP_2)
+            .extension(Version::V2_0)
+            .body(Empty)
+            .unwrap();
+        assert!(matches!(
+            Request::try_from(http_request),
+            Err(HttpConversionError::UnsupportedVersion(_))
+        ));
+
+        let mut request = Request::builder(Method::Options, Version::V1_0).empty();
+        request.set_method(Method::Extension(String::from("FOO BAR")));
+        assert_eq!(
+            http::Request::try_from(request).err(),
+            Some(HttpConversionError::InvalidMethod(String::from("FOO BAR")))
+        );
+    }
+
+    #[test]
+    fn test_response_roundtrip() {
+        let response = Response::builder(Version::V1_0, StatusCode::SessionNotFound)
+            .header(headers::CSEQ, "3")
+            .empty();
+
+        let http_response = http::Response::try_from(response.clone()).unwrap();
+        assert_eq!(http_response.status().as_u16(), 454);
+        assert_eq!(http_response.version(), http::Version::HTTP_11);
+        assert_eq!(http_response.extensions().get(), Some(&Version::V1_0));
+        assert_eq!(http_response.headers()["cseq"], "3");
+
+        assert_eq!(Response::try_from(http_response).unwrap(), response);
+
+        let http_response = http::Response::builder()
+            .version(http::Version::HTTP_10)
+            .extension(Version::V1_0)
+            .body(Empty)
+            .unwrap();
+        assert!(matches!(
+            Response::try_from(http_response),
+            Err(HttpConversionError::UnsupportedVersion(_))
+        ));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Extension(1000)).empty();
+        assert_eq!(
+            http::Response::try_from(response).err(),
+            Some(HttpConversionError::InvalidStatusCode(1000))
+        );
+    }
+
+    #[test]
+    fn test_http_tunnel() {
+        let request = Request::builder(Method::SetParameter, Version::V1_0)
+            .request_uri(Url::parse("rtsp://example.com:8554/test?a=b").unwrap())
+            .header(headers::CSEQ, "4")
+            .header(headers::CONTENT_TYPE, "text/parameters")
+            .build(Vec::from(&b"foo: bar\r\n"[..]));
+
+        let mut data = Vec::new();
+        request.write(&mut data).unwrap();
+
+        let http_request = request.to_http_tunnel().unwrap();
+        assert_eq!(http_request.uri(), "/test?a=b");
+        assert_eq!(
+            http_request.headers()[http::header::CONTENT_TYPE],
+            RTSP_TUNNELLED_CONTENT_TYPE
+        );
+        assert_eq!(base64::decode(http_request.body()).unwrap(), data);
+        assert_eq!(Request::from_http_tunnel(&http_request).unwrap(), request);
+
+        let wildcard = Request::builder(Method::Options, Version::V1_0).empty();
+        assert_eq!(wildcard.to_http_tunnel().unwrap().uri(), "/");
+
+        let get = http::Request::get("/test")
+            .header(http::header::CONTENT_TYPE, RTSP_TUNNELLED_CONTENT_TYPE)
+            .body(Vec::new())
+            .unwrap();
+        assert_eq!(
+            Request::from_http_tunnel(&get),
+            Err(HttpConversionError::NotTunnelled)
+        );
+
+        let garbage = http::Request::post("/test")
+            .header(http::header::CONTENT_TYPE, RTSP_TUNNELLED_CONTENT_TYPE)
+            .body(base64::encode(b"GARBAGE").into_bytes())
+            .unwrap();
+        assert_eq!(
+            Request::from_http_tunnel(&garbage),
+            Err(HttpConversionError::InvalidTunnelBody)
+        );
+    }
+}
@@ -113,14 +113,38 @@
 //!
 //! More details about serializing can be found at [`Message::write`](enum.Message.html#method.write).
 
+#[cfg(any(feature = "getrandom", feature = "http"))]
+mod base64;
+mod body;
+pub use body::*;
 mod message;
 pub use message::*;
+mod message_parser;
+pub use message_parser::*;
 // TODO: Maybe make this public at a later time
 mod message_ref;
 pub(crate) use message_ref::*;
 mod nom_extensions;
 mod parser;
+mod pipeline;
+pub use pipeline::*;
+mod rtsp_url;
+pub use rtsp_url::*;
 mod serializer;
+mod session_watchdog;
+pub use session_watchdog::*;
+mod text_parameters;
+pub use text_parameters::*;
+
+#[cfg(feature = "http")]
+mod http_compat;
+#[cfg(feature = "http")]
+pub use http_compat::*;
+
+#[cfg(feature = "sdp")]
+mod sdp_body;
+#[cfg(feature = "sdp")]
+pub use sdp_body::*;
 
 pub mod headers;
 pub use headers::{HeaderName, HeaderValue, Headers};
@@ -143,6 +167,59 @@ pub enum Version {
     V2_0,
 }
 
+impl Version {
+    /// Get the wire representation of the version, e.g. `RTSP/2.0`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Version::V1_0 => "RTSP/1.0",
+            Version::V2_0 => "RTSP/2.0",
+        }
+    }
+
+    /// Parse the wire representation of a version, e.g. `RTSP/2.0`.
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     rtsp_types::Version::from_wire_str("RTSP/1.0"),
+    ///     Ok(rtsp_types::Version::V1_0),
+    /// );
+    /// assert_eq!(
+    ///     rtsp_types::Version::from_wire_str("RTSP/3.0"),
+    ///     Err(rtsp_types::VersionError(String::from("RTSP/3.0"))),
+    /// );
+    /// ```
+    pub fn from_wire_str(s: &str) -> Result<Version, VersionError> {
+        match s {
+            "RTSP/1.0" => Ok(Version::V1_0),
+            "RTSP/2.0" => Ok(Version::V2_0),
+            _ => Err(VersionError(String::from(s))),
+        }
+    }
+
+    /// Checks if messages of this version can be exchanged with a peer using `other`.
+    ///
+    /// Only versions with the same major version are compatible with each other. RTSP 1.0 and
+    /// RTSP 2.0 are not compatible, see
+    /// [RFC 7826 section 7.1.1](https://tools.ietf.org/html/rfc7826#section-7.1.1).
+    pub fn is_compatible_with(&self, other: Version) -> bool {
+        *self == other
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Version {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::from_wire_str(s)
+    }
+}
+
 /// RTSP response status codes.
 ///
 /// These are defined in [RFC 7826 section 17](https://tools.ietf.org/html/rfc7826#section-17)
@@ -400,6 +477,32 @@ impl From<StatusCode> for u16 {
     }
 }
 
+/// Parses the three digit numeric value of a `StatusCode`.
+///
+/// Unknown status codes are represented as `StatusCode::Extension`.
+///
+/// ```rust
+/// assert_eq!("200".parse::<rtsp_types::StatusCode>(), Ok(rtsp_types::StatusCode::Ok));
+/// assert_eq!(
+///     "299".parse::<rtsp_types::StatusCode>(),
+///     Ok(rtsp_types::StatusCode::Extension(299)),
+/// );
+/// assert!("OK".parse::<rtsp_types::StatusCode>().is_err());
+/// ```
+impl std::str::FromStr for StatusCode {
+    type Err = StatusCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 3 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(StatusCodeError(String::from(s)));
+        }
+
+        s.parse::<u16>()
+            .map(StatusCode::from)
+            .map_err(|_| StatusCodeError(String::from(s)))
+    }
+}
+
 /// Provides the default reason phrase for the `StatusCode`.
 impl fmt::Display for StatusCode {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
@@ -532,3 +635,90 @@ impl From<std::io::Error> for WriteError {
         WriteError::IoError(v)
     }
 }
+
+/// Error while building a `SETUP` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupError {
+    /// No transport was proposed.
+    NoTransports,
+    /// No interleaved channels are available anymore.
+    NoChannelsAvailable,
+}
+
+impl std::error::Error for SetupError {}
+
+impl std::fmt::Display for SetupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            SetupError::NoTransports => write!(f, "No transports"),
+            SetupError::NoChannelsAvailable => write!(f, "No interleaved channels available"),
+        }
+    }
+}
+
+/// Invalid `text/parameters` parameter name or value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidParameterError;
+
+impl std::error::Error for InvalidParameterError {}
+
+impl std::fmt::Display for InvalidParameterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Invalid parameter")
+    }
+}
+
+/// Error while preparing a request for forwarding by a proxy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForwardError {
+    /// The `Max-Forwards` header reached zero.
+    MaxForwardsExceeded,
+    /// The `Max-Forwards` header could not be parsed.
+    InvalidMaxForwards,
+}
+
+impl std::error::Error for ForwardError {}
+
+impl std::fmt::Display for ForwardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            ForwardError::MaxForwardsExceeded => write!(f, "Max-Forwards exceeded"),
+            ForwardError::InvalidMaxForwards => write!(f, "Invalid Max-Forwards header"),
+        }
+    }
+}
+
+impl From<headers::MaxForwardsError> for ForwardError {
+    fn from(_: headers::MaxForwardsError) -> Self {
+        ForwardError::MaxForwardsExceeded
+    }
+}
+
+/// Unknown RTSP version.
+///
+/// Contains the version string that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError(pub String);
+
+impl std::error::Error for VersionError {}
+
+impl std::fmt::Display for VersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Unknown RTSP version '{}'", self.0)
+    }
+}
+
+/// Invalid RTSP status code.
+///
+/// Contains the status code string that could not be parsed. Status codes must consist of exactly
+/// three digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusCodeError(pub String);
+
+impl std::error::Error for StatusCodeError {}
+
+impl std::fmt::Display for StatusCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Invalid RTSP status code '{}'", self.0)
+    }
+}
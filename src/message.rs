@@ -6,6 +6,8 @@ use super::*;
 
 use crate::headers::{TypedAppendableHeader, TypedHeader};
 
+use std::convert::TryFrom;
+
 /// Enum holding all possible RTSP message types.
 ///
 /// A `Message` can be a [`Request`](struct.Request.html), a [`Response`](struct.Response.html) or
@@ -13,7 +15,7 @@ use crate::headers::{TypedAppendableHeader, TypedHeader};
 ///
 /// The body of the message is generic and usually a type that implements `AsRef<[u8]>`. For empty
 /// bodies there also exists the [`Empty`](struct.Empty.html) type.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(PartialEq, Eq, Clone)]
 pub enum Message<Body> {
     /// Request message
     Request(Request<Body>),
@@ -23,6 +25,80 @@ pub enum Message<Body> {
     Data(Data<Body>),
 }
 
+/// Lazily serialized wire representation of a message.
+///
+/// This is empty unless the `cached-bytes` feature is enabled, and is reset whenever the message is
+/// modified.
+// OnceLock requires Rust 1.70 but is only used with the `cached-bytes` feature
+#[cfg_attr(feature = "cached-bytes", allow(clippy::incompatible_msrv))]
+#[derive(Clone, Default)]
+pub(crate) struct WireCache(#[cfg(feature = "cached-bytes")] std::sync::OnceLock<Vec<u8>>);
+
+impl WireCache {
+    fn invalidate(&mut self) {
+        *self = WireCache::default();
+    }
+
+    #[cfg(feature = "cached-bytes")]
+    #[allow(clippy::incompatible_msrv)]
+    fn get_or_init(&self, f: impl FnOnce() -> Vec<u8>) -> &[u8] {
+        self.0.get_or_init(f)
+    }
+}
+
+impl fmt::Debug for WireCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("WireCache")
+    }
+}
+
+/// The cache is not part of the message's value.
+impl PartialEq for WireCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for WireCache {}
+
+/// Debug representation of a message body.
+///
+/// Only the first 200 bytes are printed as large bodies, e.g. SDP or media data, would otherwise
+/// flood the debug output.
+struct DebugBody<'a>(&'a [u8]);
+
+impl<'a> fmt::Debug for DebugBody<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use std::fmt::Write;
+
+        const MAX_LEN: usize = 200;
+
+        f.write_str("b\"")?;
+        for b in self.0.iter().take(MAX_LEN) {
+            for c in std::ascii::escape_default(*b) {
+                f.write_char(char::from(c))?;
+            }
+        }
+        f.write_str("\"")?;
+
+        if self.0.len() > MAX_LEN {
+            write!(f, " [... {} more bytes]", self.0.len() - MAX_LEN)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Body: AsRef<[u8]>> fmt::Debug for Message<Body> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Request(request) => f.debug_tuple("Request").field(request).finish(),
+            Message::Response(response) => f.debug_tuple("Response").field(response).finish(),
+            Message::Data(data) => f.debug_tuple("Data").field(data).finish(),
+        }
+    }
+}
+
 impl<Body> From<Request<Body>> for Message<Body> {
     fn from(v: Request<Body>) -> Self {
         Message::Request(v)
@@ -42,7 +118,7 @@ impl<Body> From<Data<Body>> for Message<Body> {
 }
 
 impl<Body: AsRef<[u8]>> Message<Body> {
-    pub(crate) fn borrow(&self) -> MessageRef {
+    pub(crate) fn borrow(&self) -> MessageRef<'_> {
         match self {
             Message::Request(request) => MessageRef::Request(request.borrow()),
             Message::Response(response) => MessageRef::Response(response.borrow()),
@@ -88,6 +164,142 @@ impl<Body: AsRef<[u8]>> Message<Body> {
     pub fn write_len(&self) -> u64 {
         self.borrow().write_len()
     }
+
+    /// Returns all differences between this message and `other`.
+    ///
+    /// Headers that only exist in `other` are reported as added, headers that only exist in this
+    /// message are reported as removed. An empty list is returned if both messages are the same
+    /// except for their body content.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Message::from(
+    ///     rtsp_types::Request::builder(rtsp_types::Method::Options, rtsp_types::Version::V2_0)
+    ///         .header(rtsp_types::headers::CSEQ, "1")
+    ///         .build(Vec::new()),
+    /// );
+    /// let other = rtsp_types::Message::from(
+    ///     rtsp_types::Request::builder(rtsp_types::Method::Options, rtsp_types::Version::V2_0)
+    ///         .header(rtsp_types::headers::CSEQ, "2")
+    ///         .build(Vec::new()),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     request.diff(&other),
+    ///     vec![rtsp_types::MessageDiff::HeaderChanged {
+    ///         name: rtsp_types::headers::CSEQ,
+    ///         old: "1".into(),
+    ///         new: "2".into(),
+    ///     }],
+    /// );
+    /// ```
+    pub fn diff<OtherBody: AsRef<[u8]>>(&self, other: &Message<OtherBody>) -> Vec<MessageDiff> {
+        let mut diffs = Vec::new();
+
+        let (old_line, new_line) = (self.start_line(), other.start_line());
+        if old_line != new_line {
+            diffs.push(MessageDiff::StartLine {
+                old: old_line,
+                new: new_line,
+            });
+        }
+
+        let empty = Headers::new();
+        let (old_headers, new_headers) = (
+            self.headers_ref().unwrap_or(&empty),
+            other.headers_ref().unwrap_or(&empty),
+        );
+        for (name, value) in old_headers.iter() {
+            match new_headers.get(name) {
+                None => diffs.push(MessageDiff::HeaderRemoved {
+                    name: name.clone(),
+                    value: value.clone(),
+                }),
+                Some(new_value) if new_value != value => diffs.push(MessageDiff::HeaderChanged {
+                    name: name.clone(),
+                    old: value.clone(),
+                    new: new_value.clone(),
+                }),
+                Some(_) => (),
+            }
+        }
+        for (name, value) in new_headers.iter() {
+            if old_headers.get(name).is_none() {
+                diffs.push(MessageDiff::HeaderAdded {
+                    name: name.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        let (old_len, new_len) = (self.body_ref().len(), other.body_ref().len());
+        if old_len != new_len {
+            diffs.push(MessageDiff::BodyLength {
+                old: old_len,
+                new: new_len,
+            });
+        }
+
+        diffs
+    }
+
+    fn start_line(&self) -> String {
+        match self {
+            Message::Request(request) => format!(
+                "{} {} {}",
+                <&str>::from(request.method()),
+                request.url_str(),
+                request.version(),
+            ),
+            Message::Response(response) => format!(
+                "{} {} {}",
+                response.version(),
+                u16::from(response.status()),
+                response.reason_phrase(),
+            ),
+            Message::Data(data) => format!("${}", data.channel_id()),
+        }
+    }
+
+    fn headers_ref(&self) -> Option<&Headers> {
+        match self {
+            Message::Request(request) => Some(request.as_ref()),
+            Message::Response(response) => Some(response.as_ref()),
+            Message::Data(_) => None,
+        }
+    }
+
+    fn body_ref(&self) -> &[u8] {
+        match self {
+            Message::Request(request) => request.body().as_ref(),
+            Message::Response(response) => response.body().as_ref(),
+            Message::Data(data) => data.as_ref(),
+        }
+    }
+}
+
+/// Difference between two messages as returned by [`Message::diff`](enum.Message.html#method.diff).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageDiff {
+    /// The request line, status line or data channel differs.
+    StartLine { old: String, new: String },
+    /// Header only exists in the other message.
+    HeaderAdded {
+        name: HeaderName,
+        value: HeaderValue,
+    },
+    /// Header only exists in this message.
+    HeaderRemoved {
+        name: HeaderName,
+        value: HeaderValue,
+    },
+    /// Header exists in both messages with different values.
+    HeaderChanged {
+        name: HeaderName,
+        old: HeaderValue,
+        new: HeaderValue,
+    },
+    /// The bodies have different lengths.
+    BodyLength { old: usize, new: usize },
 }
 
 impl<'a, T: From<&'a [u8]>> Message<T> {
@@ -133,6 +345,103 @@ impl<'a, T: From<&'a [u8]>> Message<T> {
 
         Ok((msg.to_owned()?, consumed))
     }
+
+    /// Try parse a message from a `&[u8]` that ends where the connection was closed.
+    ///
+    /// This behaves the same as [`Message::parse`](#method.parse) except for RTSP 1.0 responses
+    /// without `Content-Length` header but with a `Connection: close` header. Some servers send
+    /// these and signal the end of the body by closing the connection. `Message::parse` treats
+    /// such responses as having no body, while this function takes all remaining data as the
+    /// body.
+    ///
+    /// ```rust
+    /// let data = b"RTSP/1.0 200 OK\r\n\
+    ///              CSeq: 1\r\n\
+    ///              Connection: close\r\n\
+    ///              \r\n\
+    ///              v=0\r\n";
+    ///
+    /// let (_, consumed): (rtsp_types::Message<Vec<u8>>, _) =
+    ///     rtsp_types::Message::parse(data).expect("Failed to parse data");
+    /// assert_eq!(consumed, data.len() - 5);
+    ///
+    /// let (message, consumed): (rtsp_types::Message<Vec<u8>>, _) =
+    ///     rtsp_types::Message::parse_at_eof(data).expect("Failed to parse data");
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// match message {
+    ///     rtsp_types::Message::Response(ref response) => {
+    ///         assert_eq!(response.body(), b"v=0\r\n");
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn parse_at_eof<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+    ) -> Result<(Self, usize), ParseError> {
+        let buf = buf.as_ref();
+        let (msg, consumed) = MessageRef::parse_at_eof(buf)?;
+
+        Ok((msg.to_owned()?, consumed))
+    }
+
+    /// Try parse a message from a complete `&[u8]` and also return how many bytes were consumed.
+    ///
+    /// Whether the data contains a request, a response or a data message is detected
+    /// automatically. This is the same as [`Message::parse`](#method.parse) but only takes a
+    /// `&[u8]`.
+    ///
+    /// ```rust
+    /// let data = b"RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n";
+    ///
+    /// let (message, consumed) = rtsp_types::Message::<Vec<u8>>::try_from_slice(data)
+    ///     .expect("Failed to parse data");
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// assert!(matches!(message, rtsp_types::Message::Response(_)));
+    /// ```
+    pub fn try_from_slice(data: &'a [u8]) -> Result<(Self, usize), ParseError> {
+        Message::parse(data)
+    }
+
+    /// Try parse all complete messages from a `&[u8]` and also return how many bytes were
+    /// consumed.
+    ///
+    /// The messages are returned in order. Parsing stops at the first incomplete message, which
+    /// starts right after the consumed bytes. If any message fails to parse this returns
+    /// [`ParseError::Error`](enum.ParseError.html#variant.Error).
+    ///
+    /// ```rust
+    /// let data = b"RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n\
+    ///              RTSP/2.0 200 OK\r\nCSeq: 2\r\n\r\n\
+    ///              RTSP/2.0 200";
+    ///
+    /// let (messages, consumed) = rtsp_types::Message::<Vec<u8>>::parse_all(data)
+    ///     .expect("Failed to parse data");
+    ///
+    /// assert_eq!(messages.len(), 2);
+    /// assert_eq!(consumed, data.len() - 12);
+    /// ```
+    pub fn parse_all<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+    ) -> Result<(Vec<Self>, usize), ParseError> {
+        let buf = buf.as_ref();
+
+        let mut messages = Vec::new();
+        let mut consumed = 0;
+        while consumed < buf.len() {
+            match MessageRef::parse(&buf[consumed..]) {
+                Ok((msg, len)) => {
+                    messages.push(msg.to_owned()?);
+                    consumed += len;
+                }
+                Err(ParseError::Incomplete(_)) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok((messages, consumed))
+    }
 }
 
 /// RTSP method.
@@ -170,7 +479,7 @@ pub enum Method {
 }
 
 impl Method {
-    pub(crate) fn borrow(&self) -> MethodRef {
+    pub(crate) fn borrow(&self) -> MethodRef<'_> {
         match self {
             Method::Describe => MethodRef::Describe,
             Method::GetParameter => MethodRef::GetParameter,
@@ -187,6 +496,21 @@ impl Method {
             Method::Extension(s) => MethodRef::Extension(s),
         }
     }
+
+    /// Checks if requests with this method carry a body.
+    ///
+    /// This is the case for `ANNOUNCE` and `SET_PARAMETER`. `GET_PARAMETER` requests can
+    /// optionally carry a body but don't require one.
+    pub fn expects_request_body(&self) -> bool {
+        matches!(self, Method::Announce | Method::SetParameter)
+    }
+
+    /// Checks if successful responses to requests with this method carry a body.
+    ///
+    /// This is the case for `DESCRIBE` and `GET_PARAMETER`.
+    pub fn expects_response_body(&self) -> bool {
+        matches!(self, Method::Describe | Method::GetParameter)
+    }
 }
 
 /// Parses a method from a `&str`.
@@ -196,6 +520,17 @@ impl<'a> From<&'a str> for Method {
     }
 }
 
+/// Parses a method from a `&str`.
+///
+/// This never fails as unknown methods are represented as `Method::Extension`.
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Method::from(s))
+    }
+}
+
 /// Converts a method into a `&str`.
 impl<'a> From<&'a Method> for &'a str {
     fn from(v: &'a Method) -> Self {
@@ -255,13 +590,14 @@ impl PartialEq<Method> for &Method {
 ///     .header(rtsp_types::headers::CONTENT_TYPE, "text/parameters")
 ///     .build(Vec::from(&b"barparam: barstuff"[..]));
 /// ```
-#[derive(Debug, Clone, Eq)]
+#[derive(Clone, Eq)]
 pub struct Request<Body> {
     pub(crate) method: Method,
     pub(crate) request_uri: Option<Url>,
     pub(crate) version: Version,
     pub(crate) headers: Headers,
     pub(crate) body: Body,
+    pub(crate) wire: WireCache,
 }
 
 impl<BodyA, BodyB: PartialEq<BodyA>> PartialEq<Request<BodyA>> for Request<BodyB> {
@@ -279,10 +615,283 @@ impl Request<Empty> {
     pub fn builder(method: Method, version: Version) -> RequestBuilder {
         RequestBuilder::new(method, version)
     }
+
+    /// Build a new `DESCRIBE` request for the given URL.
+    ///
+    /// The `Accept` header is set to the given media types, or to `application/sdp` if none are
+    /// given. The `CSeq` header is set to `1`.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::describe(
+    ///     rtsp_types::Version::V2_0,
+    ///     rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"),
+    ///     &[],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::ACCEPT).unwrap(),
+    ///     "application/sdp",
+    /// );
+    /// assert_eq!(request.header(&rtsp_types::headers::CSEQ).unwrap(), "1");
+    /// ```
+    pub fn describe(version: Version, url: Url, accept: &[headers::MediaTypeRange]) -> Self {
+        let accept = if accept.is_empty() {
+            headers::Accept::from(vec![headers::MediaTypeRange {
+                type_: Some(headers::MediaType::Application),
+                subtype: Some(String::from("sdp")),
+                params: Vec::new(),
+            }])
+        } else {
+            headers::Accept::from(accept)
+        };
+
+        RequestBuilder::new(Method::Describe, version)
+            .request_uri(url)
+            .typed_header(&headers::CSeq::from(1))
+            .typed_header(&accept)
+            .empty()
+    }
+
+    /// Build a new `SETUP` request for the given URL with the proposed transports.
+    ///
+    /// RTP transports over TCP that don't specify interleaved channels yet get the next two
+    /// available channels from `channels` assigned. Interleaved channels that are already
+    /// specified are marked as in use in `channels`.
+    ///
+    /// At least one transport has to be proposed.
+    pub fn setup(
+        version: Version,
+        url: Url,
+        transports: &[headers::Transport],
+        channels: &mut headers::ChannelMap,
+    ) -> Result<Self, SetupError> {
+        if transports.is_empty() {
+            return Err(SetupError::NoTransports);
+        }
+
+        let mut transports = transports.to_vec();
+        let mut allocated_channels = None;
+        for transport in &mut transports {
+            let rtp = match transport {
+                headers::Transport::Rtp(rtp)
+                    if rtp.lower_transport == Some(headers::RtpLowerTransport::Tcp) =>
+                {
+                    rtp
+                }
+                _ => continue,
+            };
+
+            match rtp.params.interleaved {
+                Some((channel_start, channel_end)) => {
+                    channels.insert(channel_start);
+                    if let Some(channel_end) = channel_end {
+                        channels.insert(channel_end);
+                    }
+                }
+                None => {
+                    // All proposed transports are alternatives so they can share the same
+                    // channels.
+                    let (rtp_channel, rtcp_channel) = match allocated_channels {
+                        Some(allocated_channels) => allocated_channels,
+                        None => {
+                            let pair = channels
+                                .allocate_pair()
+                                .ok_or(SetupError::NoChannelsAvailable)?;
+                            allocated_channels = Some(pair);
+                            pair
+                        }
+                    };
+
+                    rtp.params.interleaved = Some((rtp_channel, Some(rtcp_channel)));
+                }
+            }
+        }
+
+        Ok(RequestBuilder::new(Method::Setup, version)
+            .request_uri(url)
+            .typed_header(&headers::Transports::from(transports))
+            .empty())
+    }
+
+    /// Build a new `PLAY` request for the given URL and session.
+    ///
+    /// If no range is given then no `Range` header is added, which means that playback starts
+    /// from the current position.
+    pub fn play(
+        version: Version,
+        url: Url,
+        session: &headers::Session,
+        range: Option<headers::Range>,
+    ) -> Self {
+        let mut builder = RequestBuilder::new(Method::Play, version)
+            .request_uri(url)
+            .typed_header(session);
+
+        if let Some(ref range) = range {
+            builder = builder.typed_header(range);
+        }
+
+        builder.empty()
+    }
+
+    /// Build a new `TEARDOWN` request for the given URL and session.
+    ///
+    /// For tearing down the whole session this should be the aggregate control URL. Some servers
+    /// don't accept the URL of an individual track here. See
+    /// [`teardown_track`](#method.teardown_track) for tearing down a single track of a session.
+    pub fn teardown(version: Version, url: Url, session: &headers::Session) -> Self {
+        RequestBuilder::new(Method::Teardown, version)
+            .request_uri(url)
+            .typed_header(session)
+            .empty()
+    }
+
+    /// Build a new `TEARDOWN` request for a single track of the given session.
+    ///
+    /// The URL is the control URL of the track. The other tracks of the session stay set up.
+    pub fn teardown_track(version: Version, track_url: Url, session: &headers::Session) -> Self {
+        Self::teardown(version, track_url, session)
+    }
+
+    /// Build a new keep-alive request for the given URL and session.
+    ///
+    /// This is a `GET_PARAMETER` request without body, which is the mechanism recommended by
+    /// [RFC 7826 section 13.8](https://tools.ietf.org/html/rfc7826#section-13.8) for testing the
+    /// liveness of the peer and for keeping a session from timing out.
+    pub fn keepalive(version: Version, url: Url, session: &headers::Session) -> Self {
+        RequestBuilder::new(Method::GetParameter, version)
+            .request_uri(url)
+            .typed_header(session)
+            .empty()
+    }
+
+    /// Build a new `ANNOUNCE` request for the given URL with an SDP body.
+    ///
+    /// The SDP has to be passed already serialized, see `announce_sdp()` with the `sdp` feature
+    /// for passing an SDP session description. The `Content-Type` header is set to
+    /// `application/sdp` and the `Content-Length` header is set to the length of the SDP.
+    ///
+    /// ```rust
+    /// let sdp = b"v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=Test\r\nt=0 0\r\n";
+    /// let request = rtsp_types::Request::announce(
+    ///     rtsp_types::Version::V1_0,
+    ///     rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"),
+    ///     &sdp[..],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::CONTENT_TYPE).unwrap(),
+    ///     "application/sdp",
+    /// );
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::CONTENT_LENGTH).unwrap(),
+    ///     sdp.len().to_string().as_str(),
+    /// );
+    /// ```
+    pub fn announce<Body: AsRef<[u8]>>(version: Version, url: Url, sdp: Body) -> Request<Body> {
+        RequestBuilder::new(Method::Announce, version)
+            .request_uri(url)
+            .header(crate::headers::CONTENT_TYPE, "application/sdp")
+            .build(sdp)
+    }
+
+    /// Build a new `GET_PARAMETER` request for the given URL and session.
+    ///
+    /// The requested parameter names are listed in a `text/parameters` body, one per line. All
+    /// parameter names must be valid tokens.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::get_parameter(
+    ///     rtsp_types::Version::V2_0,
+    ///     rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"),
+    ///     &rtsp_types::headers::Session::from("12345678"),
+    ///     &["packets_received", "jitter"],
+    /// )
+    /// .expect("Invalid parameters");
+    ///
+    /// assert_eq!(request.body(), b"packets_received\r\njitter\r\n");
+    /// ```
+    pub fn get_parameter(
+        version: Version,
+        url: Url,
+        session: &headers::Session,
+        params: &[&str],
+    ) -> Result<Request<Vec<u8>>, InvalidParameterError> {
+        let mut body = Vec::new();
+        for name in params {
+            if !is_parameter_name(name) {
+                return Err(InvalidParameterError);
+            }
+
+            body.extend_from_slice(name.as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+
+        Ok(Self::parameter_request(
+            Method::GetParameter,
+            version,
+            url,
+            session,
+            body,
+        ))
+    }
+
+    /// Build a new `SET_PARAMETER` request for the given URL and session.
+    ///
+    /// The parameters are set as `name: value` pairs in a `text/parameters` body, one per line.
+    /// All parameter names must be valid tokens and the values must not contain line breaks.
+    pub fn set_parameter(
+        version: Version,
+        url: Url,
+        session: &headers::Session,
+        params: &std::collections::BTreeMap<&str, &str>,
+    ) -> Result<Request<Vec<u8>>, InvalidParameterError> {
+        let mut body = Vec::new();
+        for (name, value) in params {
+            if !is_parameter_name(name) || value.contains(['\r', '\n']) {
+                return Err(InvalidParameterError);
+            }
+
+            body.extend_from_slice(name.as_bytes());
+            body.extend_from_slice(b": ");
+            body.extend_from_slice(value.as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+
+        Ok(Self::parameter_request(
+            Method::SetParameter,
+            version,
+            url,
+            session,
+            body,
+        ))
+    }
+
+    fn parameter_request(
+        method: Method,
+        version: Version,
+        url: Url,
+        session: &headers::Session,
+        body: Vec<u8>,
+    ) -> Request<Vec<u8>> {
+        let mut builder = RequestBuilder::new(method, version)
+            .request_uri(url)
+            .typed_header(session);
+
+        if !body.is_empty() {
+            builder = builder.header(crate::headers::CONTENT_TYPE, "text/parameters");
+        }
+
+        builder.build(body)
+    }
+}
+
+fn is_parameter_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(parser::is_token_char)
 }
 
 impl<Body> Request<Body> {
-    pub(crate) fn borrow(&self) -> RequestRef
+    pub(crate) fn borrow(&self) -> RequestRef<'_>
     where
         Body: AsRef<[u8]>,
     {
@@ -323,6 +932,27 @@ impl<Body> Request<Body> {
         self.borrow().write_len()
     }
 
+    /// Calculate the number of bytes needed to serialize the request without serializing it.
+    ///
+    /// This gives the same result as [`write_len`](#method.write_len) but is cheaper to
+    /// calculate, which makes it useful for allocating a buffer for [`write`](#method.write).
+    pub fn byte_len(&self) -> usize
+    where
+        Body: AsRef<[u8]>,
+    {
+        let method: &str = (&self.method).into();
+        let request_uri = self.url_str();
+
+        method.len()
+            + 1
+            + request_uri.len()
+            + 1
+            + self.version.as_str().len()
+            + 2
+            + self.headers.total_byte_len()
+            + self.body.as_ref().len()
+    }
+
     // Accessors
     /// Get the method of the request.
     pub fn method(&self) -> &Method {
@@ -331,19 +961,120 @@ impl<Body> Request<Body> {
 
     /// Set the method of the request.
     pub fn set_method(&mut self, method: Method) {
+        self.wire.invalidate();
         self.method = method;
     }
 
+    /// Checks if this request requires a body based on its method.
+    ///
+    /// See [`Method::expects_request_body`](enum.Method.html#method.expects_request_body).
+    pub fn requires_body(&self) -> bool {
+        self.method.expects_request_body()
+    }
+
     /// Get the request URI of the request.
     pub fn request_uri(&self) -> Option<&Url> {
         self.request_uri.as_ref()
     }
 
+    /// Get the request URI of the request, which can be `*` for requests that apply to the server
+    /// as a whole.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Options,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .empty();
+    ///
+    /// assert_eq!(request.url(), rtsp_types::RequestUrl::Wildcard);
+    /// assert!(request.is_wildcard());
+    /// ```
+    pub fn url(&self) -> RequestUrl<'_> {
+        match self.request_uri {
+            None => RequestUrl::Wildcard,
+            Some(ref url) => RequestUrl::Url(url),
+        }
+    }
+
+    /// Checks if the request URI is `*`.
+    pub fn is_wildcard(&self) -> bool {
+        self.request_uri.is_none()
+    }
+
+    /// Get the scheme of the request URI.
+    ///
+    /// Returns `None` if the request has no request URI or if it's not an RTSP URL. This allows
+    /// deciding whether to connect via TCP or TLS without string comparisons.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Describe,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .request_uri(rtsp_types::Url::parse("rtsps://example.com/test").expect("Invalid URI"))
+    ///     .empty();
+    ///
+    /// assert_eq!(request.scheme(), Some(rtsp_types::RtspScheme::Rtsps));
+    /// ```
+    pub fn scheme(&self) -> Option<RtspScheme> {
+        self.request_uri.as_ref().and_then(RtspScheme::from_url)
+    }
+
+    /// Get the request URI of the request as a `&str`.
+    ///
+    /// This returns `*` if the request has no request URI, i.e. the request applies to the
+    /// server as a whole.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Options,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .empty();
+    /// assert_eq!(request.url_str(), "*");
+    ///
+    /// let request = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Describe,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .request_uri(rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"))
+    ///     .empty();
+    /// assert_eq!(request.url_str(), "rtsp://example.com/test");
+    /// ```
+    pub fn url_str(&self) -> &str {
+        self.url().as_str()
+    }
+
     /// Set the request URI of the request.
     pub fn set_request_uri(&mut self, request_uri: Option<Url>) {
+        self.wire.invalidate();
         self.request_uri = request_uri;
     }
 
+    /// Get a mutable reference to the request URI of the request.
+    ///
+    /// This allows modifying parts of the URI in place, e.g. replacing the host.
+    ///
+    /// ```rust
+    /// let mut request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::Options,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .request_uri(rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"))
+    /// .empty();
+    ///
+    /// if let Some(uri) = request.request_uri_mut() {
+    ///     uri.set_host(Some("192.0.2.1")).expect("Invalid host");
+    /// }
+    ///
+    /// assert_eq!(request.request_uri().unwrap().as_str(), "rtsp://192.0.2.1/test");
+    /// ```
+    pub fn request_uri_mut(&mut self) -> Option<&mut Url> {
+        self.wire.invalidate();
+        self.request_uri.as_mut()
+    }
+
     /// Get the version of the request.
     pub fn version(&self) -> Version {
         self.version
@@ -351,6 +1082,7 @@ impl<Body> Request<Body> {
 
     /// Set the version of the request.
     pub fn set_version(&mut self, version: Version) {
+        self.wire.invalidate();
         self.version = version;
     }
 
@@ -365,6 +1097,21 @@ impl<Body> Request<Body> {
         self.body
     }
 
+    /// Clone the request without its body.
+    ///
+    /// The method, request URI, version and headers are cloned, including the `Content-Length`
+    /// header. This is useful for logging or for forwarding the request with a different body.
+    pub fn clone_without_body(&self) -> Request<Empty> {
+        Request {
+            method: self.method.clone(),
+            request_uri: self.request_uri.clone(),
+            version: self.version,
+            headers: self.headers.clone(),
+            body: Empty,
+            wire: WireCache::default(),
+        }
+    }
+
     /// Modify the body of the request with a closure.
     ///
     /// This replaces the `Content-Length` header of the message with the length of the new body.
@@ -378,6 +1125,7 @@ impl<Body> Request<Body> {
             version,
             mut headers,
             body,
+            wire: _,
         } = self;
 
         let new_body = func(body);
@@ -400,6 +1148,7 @@ impl<Body> Request<Body> {
             version,
             headers,
             body: new_body,
+            wire: WireCache::default(),
         }
     }
 
@@ -413,6 +1162,7 @@ impl<Body> Request<Body> {
             version,
             mut headers,
             body: _body,
+            wire: _,
         } = self;
 
         {
@@ -433,6 +1183,7 @@ impl<Body> Request<Body> {
             version,
             headers,
             body: new_body,
+            wire: WireCache::default(),
         }
     }
 
@@ -441,6 +1192,7 @@ impl<Body> Request<Body> {
     ///
     /// Additional values are comma separated as defined in [RFC 7826 section 5.2](https://tools.ietf.org/html/rfc7826#section-5.2).
     pub fn append_header<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
+        self.wire.invalidate();
         let value = value.into();
         self.headers.append(name, value);
     }
@@ -451,12 +1203,14 @@ impl<Body> Request<Body> {
     ///
     /// See [`append`](#method.append) for appending additional values to a header.
     pub fn insert_header<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
+        self.wire.invalidate();
         let value = value.into();
         self.headers.insert(name, value);
     }
 
     /// Append a typed RTSP header with its value.
     pub fn append_typed_header<H: TypedAppendableHeader>(&mut self, header: &H) {
+        self.wire.invalidate();
         self.headers.append_typed(header);
     }
 
@@ -464,11 +1218,13 @@ impl<Body> Request<Body> {
     ///
     /// If a header with the same name already exists then its value will be replaced.
     pub fn insert_typed_header<H: TypedHeader>(&mut self, header: &H) {
+        self.wire.invalidate();
         self.headers.insert_typed(header);
     }
 
     /// Removes and RTSP header if it exists.
     pub fn remove_header(&mut self, name: &HeaderName) {
+        self.wire.invalidate();
         self.headers.remove(name);
     }
 
@@ -478,15 +1234,32 @@ impl<Body> Request<Body> {
     }
 
     /// Gets a typed RTSP header value if it exists.
+    ///
+    /// The header is parsed according to the RTSP version of the message.
     pub fn typed_header<H: TypedHeader>(&self) -> Result<Option<H>, headers::HeaderParseError> {
-        self.headers.get_typed()
+        self.headers.get_typed_versioned(self.version)
     }
 
     /// Gets a mutable reference to an RTSP header value if it exists.
     pub fn header_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
+        self.wire.invalidate();
         self.headers.get_mut(name)
     }
 
+    /// Checks if `response` is the response to this request.
+    ///
+    /// This compares the `CSeq` headers of both messages and returns `false` if either of them
+    /// is missing or invalid.
+    pub fn matches_response<B>(&self, response: &Response<B>) -> bool {
+        match (
+            self.typed_header::<headers::CSeq>(),
+            response.typed_header::<headers::CSeq>(),
+        ) {
+            (Ok(Some(request_cseq)), Ok(Some(response_cseq))) => request_cseq == response_cseq,
+            _ => false,
+        }
+    }
+
     /// Iterator over all header name and value pairs.
     pub fn headers(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
         self.headers.iter()
@@ -501,6 +1274,183 @@ impl<Body> Request<Body> {
     pub fn header_values(&self) -> impl Iterator<Item = &HeaderValue> {
         self.headers.values()
     }
+
+    /// Decrement the `Max-Forwards` header, if present.
+    ///
+    /// If it is already zero then the request must not be forwarded anymore and an error is
+    /// returned.
+    pub fn decrement_max_forwards(&mut self) -> Result<(), ForwardError> {
+        let max_forwards = self
+            .typed_header::<headers::MaxForwards>()
+            .map_err(|_| ForwardError::InvalidMaxForwards)?;
+
+        if let Some(max_forwards) = max_forwards {
+            self.insert_typed_header(&max_forwards.decrement()?);
+        }
+
+        Ok(())
+    }
+
+    /// Prepare this request for being forwarded by a proxy with the given address.
+    ///
+    /// This is the same as [`into_forwarded`](#method.into_forwarded) with a `Via` entry for a
+    /// TCP connection of the given version, sent by `proxy_addr`.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::Options,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .header(rtsp_types::headers::MAX_FORWARDS, "5")
+    /// .empty();
+    ///
+    /// let request = request
+    ///     .forwarded_for("::1".parse().unwrap(), rtsp_types::Version::V2_0)
+    ///     .expect("Can't forward request");
+    ///
+    /// assert_eq!(request.header(&rtsp_types::headers::VIA).unwrap(), "RTSP/2.0/TCP [::1]");
+    /// assert_eq!(request.header(&rtsp_types::headers::MAX_FORWARDS).unwrap(), "4");
+    /// ```
+    pub fn forwarded_for(
+        self,
+        proxy_addr: std::net::IpAddr,
+        version: Version,
+    ) -> Result<Self, ForwardError> {
+        let sent_by = match proxy_addr {
+            std::net::IpAddr::V4(addr) => addr.to_string(),
+            std::net::IpAddr::V6(addr) => format!("[{addr}]"),
+        };
+
+        self.into_forwarded(headers::ViaEntry::new(version, "TCP", &sent_by))
+    }
+
+    /// Prepare this request for being forwarded by a proxy.
+    ///
+    /// This follows the rules of [RFC 7826 section 16](https://tools.ietf.org/html/rfc7826#section-16):
+    ///
+    ///  * The `Connection` header and all headers listed in it are removed.
+    ///  * The `Proxy-Require` and `Proxy-Authorization` headers are removed.
+    ///  * `via_entry` is appended to the `Via` header.
+    ///  * `Max-Forwards` is decremented, if present. If it is already zero then the request must
+    ///    not be forwarded anymore and an error is returned.
+    ///
+    /// ```rust
+    /// let request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::Options,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .header(rtsp_types::headers::CONNECTION, "X-Hop")
+    /// .header(rtsp_types::HeaderName::from_static_str("X-Hop").unwrap(), "1")
+    /// .header(rtsp_types::headers::VIA, "RTSP/2.0/TCP client.example.com")
+    /// .empty();
+    ///
+    /// let request = request
+    ///     .into_forwarded(rtsp_types::headers::ViaEntry::new(
+    ///         rtsp_types::Version::V2_0,
+    ///         "TCP",
+    ///         "proxy.example.com",
+    ///     ))
+    ///     .expect("Can't forward request");
+    ///
+    /// assert_eq!(request.header(&rtsp_types::headers::CONNECTION), None);
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::HeaderName::from_static_str("X-Hop").unwrap()),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::VIA).unwrap(),
+    ///     "RTSP/2.0/TCP client.example.com, RTSP/2.0/TCP proxy.example.com",
+    /// );
+    /// ```
+    pub fn into_forwarded(mut self, via_entry: headers::ViaEntry) -> Result<Self, ForwardError> {
+        use std::convert::TryFrom;
+
+        self.decrement_max_forwards()?;
+        self.wire.invalidate();
+
+        if let Some(connection) = self.headers.get(&headers::CONNECTION) {
+            let hop_by_hop = connection
+                .as_str()
+                .split(',')
+                .filter_map(|name| HeaderName::try_from(name.trim()).ok())
+                .collect::<Vec<_>>();
+            for name in &hop_by_hop {
+                self.headers.remove(name);
+            }
+            self.headers.remove(&headers::CONNECTION);
+        }
+
+        self.headers.remove(&headers::PROXY_REQUIRE);
+        self.headers.remove(&headers::PROXY_AUTHORIZATION);
+
+        self.headers.append(headers::VIA, via_entry.to_string());
+
+        Ok(self)
+    }
+}
+
+impl<'a, T: From<&'a [u8]>> Request<T> {
+    /// Try parse a request from a `&[u8]` and also return how many bytes were consumed.
+    ///
+    /// This behaves the same as [`Message::parse`](enum.Message.html#method.parse) but fails with
+    /// [`ParseError::Error`](enum.ParseError.html#variant.Error) if the data contains a
+    /// response or data message instead of a request.
+    ///
+    /// ```rust
+    /// let data = b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n";
+    ///
+    /// let (request, consumed) =
+    ///     rtsp_types::Request::<Vec<u8>>::parse(data).expect("Failed to parse data");
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// assert_eq!(request.method(), rtsp_types::Method::Options);
+    /// ```
+    pub fn parse<B: AsRef<[u8]> + 'a + ?Sized>(buf: &'a B) -> Result<(Self, usize), ParseError> {
+        match Message::parse(buf)? {
+            (Message::Request(request), consumed) => Ok((request, consumed)),
+            _ => Err(ParseError::Error),
+        }
+    }
+}
+
+/// Parses a complete request from a `&[u8]`.
+///
+/// Fails with [`ParseError::Error`](enum.ParseError.html#variant.Error) if the data contains
+/// anything else than exactly one request. Use [`Request::parse`](#method.parse) to parse a
+/// request from the start of a buffer and to get the number of bytes consumed.
+impl<'a, T: From<&'a [u8]>> TryFrom<&'a [u8]> for Request<T> {
+    type Error = ParseError;
+
+    fn try_from(buf: &'a [u8]) -> Result<Self, ParseError> {
+        match Request::parse(buf)? {
+            (request, consumed) if consumed == buf.len() => Ok(request),
+            _ => Err(ParseError::Error),
+        }
+    }
+}
+
+/// Parses a complete request from a `&str`.
+///
+/// This behaves the same as the `TryFrom<&[u8]>` implementation and is mostly useful for tests.
+impl<'a, T: From<&'a [u8]>> TryFrom<&'a str> for Request<T> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, ParseError> {
+        Request::try_from(s.as_bytes())
+    }
+}
+
+/// Prints all fields but only the first 200 bytes of the body.
+impl<Body: AsRef<[u8]>> fmt::Debug for Request<Body> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Request")
+            .field("method", &self.method)
+            .field("request_uri", &self.request_uri)
+            .field("version", &self.version)
+            .field("headers", &self.headers)
+            .field("body", &DebugBody(self.body.as_ref()))
+            .finish()
+    }
 }
 
 impl<Body> AsRef<Headers> for Request<Body> {
@@ -509,8 +1459,26 @@ impl<Body> AsRef<Headers> for Request<Body> {
     }
 }
 
+/// Fully serialized wire representation of the request.
+///
+/// The request is serialized once on first access and the result is stored inside the request.
+/// Modifying the request resets the cached serialization. Modifications of the body via interior
+/// mutability are not detected.
+#[cfg(feature = "cached-bytes")]
+impl<Body: AsRef<[u8]>> AsRef<[u8]> for Request<Body> {
+    fn as_ref(&self) -> &[u8] {
+        self.wire.get_or_init(|| {
+            let mut data = Vec::with_capacity(self.byte_len());
+            self.write(&mut data)
+                .expect("Serializing to a Vec can't fail");
+            data
+        })
+    }
+}
+
 impl<Body> AsMut<Headers> for Request<Body> {
     fn as_mut(&mut self) -> &mut Headers {
+        self.wire.invalidate();
         &mut self.headers
     }
 }
@@ -529,6 +1497,7 @@ impl RequestBuilder {
             version,
             headers: Headers::new(),
             body: Empty,
+            wire: WireCache::default(),
         })
     }
 
@@ -581,8 +1550,42 @@ impl RequestBuilder {
             version: self.0.version,
             headers: self.0.headers,
             body,
+            wire: WireCache::default(),
         }
     }
+
+    /// Build a request with a [`Body`](enum.Body.html).
+    ///
+    /// The body is serialized with [`Body::to_bytes`](enum.Body.html#method.to_bytes) and
+    /// otherwise this is the same as [`build`](#method.build). In addition the `Content-Type`
+    /// header is inserted if the content type of the body is known.
+    ///
+    /// ```rust
+    /// let mut params = rtsp_types::TextParameters::new();
+    /// params.insert("volume", "0.5");
+    ///
+    /// let request = rtsp_types::Request::builder(
+    ///     rtsp_types::Method::SetParameter,
+    ///     rtsp_types::Version::V2_0,
+    /// )
+    /// .body(rtsp_types::Body::from(params));
+    ///
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::CONTENT_TYPE).unwrap(),
+    ///     "text/parameters",
+    /// );
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::CONTENT_LENGTH).unwrap(),
+    ///     "13",
+    /// );
+    /// ```
+    pub fn body(mut self, body: Body) -> Request<bytes::Bytes> {
+        if let Some(content_type) = body.content_type() {
+            self.0.headers.insert_typed(&content_type);
+        }
+
+        self.build(bytes::Bytes::from(body))
+    }
 }
 
 /// RTSP Response.
@@ -602,13 +1605,14 @@ impl RequestBuilder {
 ///     .header(rtsp_types::headers::CSEQ, "1")
 ///     .empty();
 /// ```
-#[derive(Debug, Clone, Eq)]
+#[derive(Clone, Eq)]
 pub struct Response<Body> {
     pub(crate) version: Version,
     pub(crate) status: StatusCode,
     pub(crate) reason_phrase: String,
     pub(crate) headers: Headers,
     pub(crate) body: Body,
+    pub(crate) wire: WireCache,
 }
 
 impl<BodyA, BodyB: PartialEq<BodyA>> PartialEq<Response<BodyA>> for Response<BodyB> {
@@ -626,10 +1630,110 @@ impl Response<Empty> {
     pub fn builder(version: Version, status: StatusCode) -> ResponseBuilder {
         ResponseBuilder::new(version, status)
     }
+
+    /// Build a new `OK` response to an `OPTIONS` request.
+    ///
+    /// The `Public` header is set to the list of supported methods.
+    ///
+    /// ```rust
+    /// let response = rtsp_types::Response::options_response(
+    ///     rtsp_types::Version::V2_0,
+    ///     &[rtsp_types::Method::Options, rtsp_types::Method::Describe],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     response.header(&rtsp_types::headers::PUBLIC).unwrap(),
+    ///     "OPTIONS, DESCRIBE",
+    /// );
+    /// ```
+    pub fn options_response(version: Version, supported_methods: &[Method]) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::Ok)
+            .typed_header(&headers::Public::from(supported_methods))
+            .empty()
+    }
+
+    /// Build a new `Continue` response.
+    ///
+    /// Informational responses must not contain a body, which is why this always returns a
+    /// response with an [`Empty`](struct.Empty.html) body.
+    pub fn continue_response(version: Version) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::Continue).empty()
+    }
+
+    /// Build a new error response with the given status code and reason phrase and no body.
+    ///
+    /// The `CSeq` header of the corresponding request still has to be set on the response.
+    ///
+    /// ```rust
+    /// let response = rtsp_types::Response::error(
+    ///     rtsp_types::Version::V2_0,
+    ///     rtsp_types::StatusCode::MethodNotAllowed,
+    ///     "Not Allowed Here",
+    /// );
+    ///
+    /// assert_eq!(response.status(), rtsp_types::StatusCode::MethodNotAllowed);
+    /// assert_eq!(response.reason_phrase(), "Not Allowed Here");
+    /// ```
+    pub fn error(
+        version: Version,
+        status: StatusCode,
+        reason: impl Into<String>,
+    ) -> Response<Empty> {
+        ResponseBuilder::new(version, status)
+            .reason_phrase(reason)
+            .empty()
+    }
+
+    /// Build a new `Not Found` error response.
+    pub fn not_found(version: Version) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::NotFound).empty()
+    }
+
+    /// Build a new `Bad Request` error response with `detail` as reason phrase.
+    pub fn bad_request(version: Version, detail: &str) -> Response<Empty> {
+        Self::error(version, StatusCode::BadRequest, detail)
+    }
+
+    /// Build a new `Session Not Found` error response.
+    pub fn session_not_found(version: Version) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::SessionNotFound).empty()
+    }
+
+    /// Build a new `Unauthorized` error response with the given authentication challenge.
+    ///
+    /// The challenge is set as `WWW-Authenticate` header.
+    ///
+    /// ```rust
+    /// let mut challenge = rtsp_types::headers::Challenge::new("Basic");
+    /// challenge.params.push((String::from("realm"), String::from("Streaming Server")));
+    ///
+    /// let response = rtsp_types::Response::www_authenticate(rtsp_types::Version::V2_0, &challenge);
+    ///
+    /// assert_eq!(response.status(), rtsp_types::StatusCode::Unauthorized);
+    /// assert_eq!(
+    ///     response.header(&rtsp_types::headers::WWW_AUTHENTICATE).unwrap(),
+    ///     "Basic realm=\"Streaming Server\"",
+    /// );
+    /// ```
+    pub fn www_authenticate(version: Version, challenge: &headers::Challenge) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::Unauthorized)
+            .typed_header(&headers::WwwAuthenticate::from(vec![challenge.clone()]))
+            .empty()
+    }
+
+    /// Build a new `Proxy Authentication Required` error response with the given authentication
+    /// challenge.
+    ///
+    /// The challenge is set as `Proxy-Authenticate` header.
+    pub fn proxy_authenticate(version: Version, challenge: &headers::Challenge) -> Response<Empty> {
+        ResponseBuilder::new(version, StatusCode::ProxyAuthenticationRequired)
+            .header(crate::headers::PROXY_AUTHENTICATE, challenge.to_string())
+            .empty()
+    }
 }
 
 impl<Body> Response<Body> {
-    pub(crate) fn borrow(&self) -> ResponseRef
+    pub(crate) fn borrow(&self) -> ResponseRef<'_>
     where
         Body: AsRef<[u8]>,
     {
@@ -670,6 +1774,26 @@ impl<Body> Response<Body> {
         self.borrow().write_len()
     }
 
+    /// Calculate the number of bytes needed to serialize the response without serializing it.
+    ///
+    /// This gives the same result as [`write_len`](#method.write_len) but is cheaper to
+    /// calculate, which makes it useful for allocating a buffer for [`write`](#method.write).
+    pub fn byte_len(&self) -> usize
+    where
+        Body: AsRef<[u8]>,
+    {
+        let status = u16::from(self.status).to_string();
+
+        self.version.as_str().len()
+            + 1
+            + status.len()
+            + 1
+            + self.reason_phrase.len()
+            + 2
+            + self.headers.total_byte_len()
+            + self.body.as_ref().len()
+    }
+
     // Accessors
     /// Get the version of the response.
     pub fn version(&self) -> Version {
@@ -678,6 +1802,7 @@ impl<Body> Response<Body> {
 
     /// Set the version of the response.
     pub fn set_version(&mut self, version: Version) {
+        self.wire.invalidate();
         self.version = version;
     }
 
@@ -687,7 +1812,11 @@ impl<Body> Response<Body> {
     }
 
     /// Set the status code of the response.
+    ///
+    /// This does not change the reason phrase, which has to be updated separately with
+    /// [`set_reason_phrase`](#method.set_reason_phrase) if needed.
     pub fn set_status(&mut self, status: StatusCode) {
+        self.wire.invalidate();
         self.status = status;
     }
 
@@ -698,6 +1827,7 @@ impl<Body> Response<Body> {
 
     /// Set the reason phrase of the response.
     pub fn set_reason_phrase<S: Into<String>>(&mut self, reason_phrase: S) {
+        self.wire.invalidate();
         self.reason_phrase = reason_phrase.into();
     }
 
@@ -712,6 +1842,22 @@ impl<Body> Response<Body> {
         self.body
     }
 
+    /// Clone the response without its body.
+    ///
+    /// The version, status code, reason phrase and headers are cloned, including the
+    /// `Content-Length` header. This is useful for logging or for forwarding the response with a
+    /// different body.
+    pub fn clone_without_body(&self) -> Response<Empty> {
+        Response {
+            version: self.version,
+            status: self.status,
+            reason_phrase: self.reason_phrase.clone(),
+            headers: self.headers.clone(),
+            body: Empty,
+            wire: WireCache::default(),
+        }
+    }
+
     /// Modify the body of the response with a closure.
     ///
     /// This replaces the `Content-Length` header of the message with the length of the new body.
@@ -725,6 +1871,7 @@ impl<Body> Response<Body> {
             reason_phrase,
             mut headers,
             body,
+            wire: _,
         } = self;
 
         let new_body = func(body);
@@ -747,6 +1894,7 @@ impl<Body> Response<Body> {
             reason_phrase,
             headers,
             body: new_body,
+            wire: WireCache::default(),
         }
     }
 
@@ -760,6 +1908,7 @@ impl<Body> Response<Body> {
             reason_phrase,
             mut headers,
             body: _body,
+            wire: _,
         } = self;
 
         {
@@ -780,6 +1929,7 @@ impl<Body> Response<Body> {
             reason_phrase,
             headers,
             body: new_body,
+            wire: WireCache::default(),
         }
     }
 
@@ -788,6 +1938,7 @@ impl<Body> Response<Body> {
     ///
     /// Additional values are comma separated as defined in [RFC 7826 section 5.2](https://tools.ietf.org/html/rfc7826#section-5.2).
     pub fn append_header<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
+        self.wire.invalidate();
         let value = value.into();
         self.headers.append(name, value);
     }
@@ -798,12 +1949,14 @@ impl<Body> Response<Body> {
     ///
     /// See [`append`](#method.append) for appending additional values to a header.
     pub fn insert_header<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
+        self.wire.invalidate();
         let value = value.into();
         self.headers.insert(name, value);
     }
 
     /// Append a typed RTSP header with its value.
     pub fn append_typed_header<H: TypedAppendableHeader>(&mut self, header: &H) {
+        self.wire.invalidate();
         self.headers.append_typed(header);
     }
 
@@ -811,11 +1964,13 @@ impl<Body> Response<Body> {
     ///
     /// If a header with the same name already exists then its value will be replaced.
     pub fn insert_typed_header<H: TypedHeader>(&mut self, header: &H) {
+        self.wire.invalidate();
         self.headers.insert_typed(header);
     }
 
     /// Removes and RTSP header if it exists.
     pub fn remove_header(&mut self, name: &HeaderName) {
+        self.wire.invalidate();
         self.headers.remove(name);
     }
 
@@ -825,12 +1980,15 @@ impl<Body> Response<Body> {
     }
 
     /// Gets a typed RTSP header value if it exists.
+    ///
+    /// The header is parsed according to the RTSP version of the message.
     pub fn typed_header<H: TypedHeader>(&self) -> Result<Option<H>, headers::HeaderParseError> {
-        self.headers.get_typed()
+        self.headers.get_typed_versioned(self.version)
     }
 
     /// Gets a mutable reference to an RTSP header value if it exists.
     pub fn header_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
+        self.wire.invalidate();
         self.headers.get_mut(name)
     }
 
@@ -848,6 +2006,105 @@ impl<Body> Response<Body> {
     pub fn header_values(&self) -> impl Iterator<Item = &HeaderValue> {
         self.headers.values()
     }
+
+    /// Checks if this is a redirect response, i.e. `Moved Permanently`, `Found` or `See Other`.
+    pub fn is_redirect(&self) -> bool {
+        matches!(
+            self.status,
+            StatusCode::MovedPermanently | StatusCode::Found | StatusCode::SeeOther
+        )
+    }
+
+    /// Returns the URL from the `Location` header of a redirect response.
+    ///
+    /// Returns `None` if this is not a redirect response or if there is no `Location` header.
+    ///
+    /// ```rust
+    /// let response = rtsp_types::Response::builder(
+    ///     rtsp_types::Version::V2_0,
+    ///     rtsp_types::StatusCode::MovedPermanently,
+    /// )
+    /// .header(rtsp_types::headers::LOCATION, "rtsp://other.example.com/test")
+    /// .empty();
+    ///
+    /// assert_eq!(
+    ///     response.redirect_url(),
+    ///     Some(rtsp_types::Url::parse("rtsp://other.example.com/test")),
+    /// );
+    /// ```
+    pub fn redirect_url(&self) -> Option<Result<Url, url::ParseError>> {
+        if !self.is_redirect() {
+            return None;
+        }
+
+        let location = self.headers.get(&headers::LOCATION)?;
+
+        Some(Url::parse(location.as_str().trim()))
+    }
+}
+
+impl<'a, T: From<&'a [u8]>> Response<T> {
+    /// Try parse a response from a `&[u8]` and also return how many bytes were consumed.
+    ///
+    /// This behaves the same as [`Message::parse`](enum.Message.html#method.parse) but fails with
+    /// [`ParseError::Error`](enum.ParseError.html#variant.Error) if the data contains a
+    /// request or data message instead of a response.
+    ///
+    /// ```rust
+    /// let data = b"RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n";
+    ///
+    /// let (response, consumed) =
+    ///     rtsp_types::Response::<Vec<u8>>::parse(data).expect("Failed to parse data");
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// assert_eq!(response.status(), rtsp_types::StatusCode::Ok);
+    /// ```
+    pub fn parse<B: AsRef<[u8]> + 'a + ?Sized>(buf: &'a B) -> Result<(Self, usize), ParseError> {
+        match Message::parse(buf)? {
+            (Message::Response(response), consumed) => Ok((response, consumed)),
+            _ => Err(ParseError::Error),
+        }
+    }
+}
+
+/// Parses a complete response from a `&[u8]`.
+///
+/// Fails with [`ParseError::Error`](enum.ParseError.html#variant.Error) if the data contains
+/// anything else than exactly one response. Use [`Response::parse`](#method.parse) to parse a
+/// response from the start of a buffer and to get the number of bytes consumed.
+impl<'a, T: From<&'a [u8]>> TryFrom<&'a [u8]> for Response<T> {
+    type Error = ParseError;
+
+    fn try_from(buf: &'a [u8]) -> Result<Self, ParseError> {
+        match Response::parse(buf)? {
+            (response, consumed) if consumed == buf.len() => Ok(response),
+            _ => Err(ParseError::Error),
+        }
+    }
+}
+
+/// Parses a complete response from a `&str`.
+///
+/// This behaves the same as the `TryFrom<&[u8]>` implementation and is mostly useful for tests.
+impl<'a, T: From<&'a [u8]>> TryFrom<&'a str> for Response<T> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, ParseError> {
+        Response::try_from(s.as_bytes())
+    }
+}
+
+/// Prints all fields but only the first 200 bytes of the body.
+impl<Body: AsRef<[u8]>> fmt::Debug for Response<Body> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Response")
+            .field("version", &self.version)
+            .field("status", &self.status)
+            .field("reason_phrase", &self.reason_phrase)
+            .field("headers", &self.headers)
+            .field("body", &DebugBody(self.body.as_ref()))
+            .finish()
+    }
 }
 
 impl<Body> AsRef<Headers> for Response<Body> {
@@ -856,8 +2113,26 @@ impl<Body> AsRef<Headers> for Response<Body> {
     }
 }
 
+/// Fully serialized wire representation of the response.
+///
+/// The response is serialized once on first access and the result is stored inside the response.
+/// Modifying the response resets the cached serialization. Modifications of the body via interior
+/// mutability are not detected.
+#[cfg(feature = "cached-bytes")]
+impl<Body: AsRef<[u8]>> AsRef<[u8]> for Response<Body> {
+    fn as_ref(&self) -> &[u8] {
+        self.wire.get_or_init(|| {
+            let mut data = Vec::with_capacity(self.byte_len());
+            self.write(&mut data)
+                .expect("Serializing to a Vec can't fail");
+            data
+        })
+    }
+}
+
 impl<Body> AsMut<Headers> for Response<Body> {
     fn as_mut(&mut self) -> &mut Headers {
+        self.wire.invalidate();
         &mut self.headers
     }
 }
@@ -876,6 +2151,7 @@ impl ResponseBuilder {
             reason_phrase: String::new(),
             headers: Headers::new(),
             body: Empty,
+            wire: WireCache::default(),
         };
 
         Self(response, None)
@@ -941,15 +2217,29 @@ impl ResponseBuilder {
             reason_phrase,
             headers: response.headers,
             body,
+            wire: WireCache::default(),
         }
     }
+
+    /// Build a response with a [`Body`](enum.Body.html).
+    ///
+    /// The body is serialized with [`Body::to_bytes`](enum.Body.html#method.to_bytes) and
+    /// otherwise this is the same as [`build`](#method.build). In addition the `Content-Type`
+    /// header is inserted if the content type of the body is known.
+    pub fn body(mut self, body: Body) -> Response<bytes::Bytes> {
+        if let Some(content_type) = body.content_type() {
+            self.0.headers.insert_typed(&content_type);
+        }
+
+        self.build(bytes::Bytes::from(body))
+    }
 }
 
 /// RTSP data message.
 ///
 /// See [RFC 7826 section 14](https://tools.ietf.org/html/rfc7826#section-14) for details about the
 /// data message.
-#[derive(Debug, Clone, Eq)]
+#[derive(Clone, Eq)]
 pub struct Data<Body> {
     pub(crate) channel_id: u8,
     pub(crate) body: Body,
@@ -962,7 +2252,7 @@ impl<BodyA, BodyB: PartialEq<BodyA>> PartialEq<Data<BodyA>> for Data<BodyB> {
 }
 
 impl<Body> Data<Body> {
-    pub(crate) fn borrow(&self) -> DataRef
+    pub(crate) fn borrow(&self) -> DataRef<'_>
     where
         Body: AsRef<[u8]>,
     {
@@ -1061,8 +2351,269 @@ impl Data<Vec<u8>> {
     }
 }
 
+/// Prints the channel id but only the first 200 bytes of the body.
+impl<Body: AsRef<[u8]>> fmt::Debug for Data<Body> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Data")
+            .field("channel_id", &self.channel_id)
+            .field("body", &DebugBody(self.body.as_ref()))
+            .finish()
+    }
+}
+
 impl<Body: AsRef<[u8]>> AsRef<[u8]> for Data<Body> {
     fn as_ref(&self) -> &[u8] {
         self.body.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_len() {
+        let request = Request::builder(Method::SetParameter, Version::V2_0)
+            .request_uri(Url::parse("rtsp://example.com/test").unwrap())
+            .header(crate::headers::CSEQ, "2")
+            .header(crate::headers::CONTENT_TYPE, "text/parameters")
+            .build(Vec::from(&b"barparam: barstuff"[..]));
+        assert_eq!(request.byte_len() as u64, request.write_len());
+
+        let request = Request::builder(Method::Options, Version::V1_0)
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+        assert_eq!(request.byte_len() as u64, request.write_len());
+
+        let response = Response::builder(Version::V2_0, StatusCode::Extension(1000))
+            .header(crate::headers::CSEQ, "1")
+            .build(Vec::from(&b"data"[..]));
+        assert_eq!(response.byte_len() as u64, response.write_len());
+    }
+
+    #[test]
+    fn test_setup() {
+        use crate::headers::{
+            ChannelMap, RtpLowerTransport, RtpProfile, RtpTransport, RtpTransportParameters,
+            Transport, Transports,
+        };
+
+        let url = Url::parse("rtsp://example.com/test/track1").unwrap();
+        let tcp = Transport::Rtp(RtpTransport {
+            profile: RtpProfile::Avp,
+            lower_transport: Some(RtpLowerTransport::Tcp),
+            params: RtpTransportParameters::default(),
+        });
+
+        let mut channels = ChannelMap::new();
+        channels.insert(1);
+
+        let request = Request::setup(
+            Version::V1_0,
+            url.clone(),
+            &[tcp.clone(), tcp.clone()],
+            &mut channels,
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.header(&crate::headers::TRANSPORT).unwrap(),
+            "RTP/AVP/TCP;interleaved=2-3,RTP/AVP/TCP;interleaved=2-3"
+        );
+        assert!(channels.contains(2));
+        assert!(channels.contains(3));
+        assert!(!channels.contains(0));
+
+        let request = Request::setup(Version::V1_0, url.clone(), &[tcp], &mut channels).unwrap();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        match transports[0] {
+            Transport::Rtp(ref rtp) => assert_eq!(rtp.params.interleaved, Some((4, Some(5)))),
+            _ => unreachable!(),
+        }
+
+        assert_eq!(
+            Request::setup(Version::V1_0, url, &[], &mut channels),
+            Err(SetupError::NoTransports)
+        );
+    }
+
+    #[cfg(feature = "cached-bytes")]
+    #[test]
+    fn test_cached_bytes() {
+        let mut request = Request::builder(Method::Options, Version::V2_0)
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+
+        let data: &[u8] = request.as_ref();
+        assert_eq!(data, b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n");
+
+        request.insert_header(crate::headers::CSEQ, "2");
+        let data: &[u8] = request.as_ref();
+        assert_eq!(data, b"OPTIONS * RTSP/2.0\r\nCSeq: 2\r\n\r\n");
+    }
+
+    #[test]
+    fn test_matches_response() {
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "01")
+            .empty();
+        assert!(request.matches_response(&response));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "2")
+            .empty();
+        assert!(!request.matches_response(&response));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok).empty();
+        assert!(!request.matches_response(&response));
+    }
+
+    #[test]
+    fn test_set_status() {
+        let mut response = Response::builder(Version::V2_0, StatusCode::NotFound)
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+        assert_eq!(response.reason_phrase(), "Not Found");
+
+        response.set_status(StatusCode::NotAcceptable);
+        assert_eq!(response.status(), StatusCode::NotAcceptable);
+        assert_eq!(response.reason_phrase(), "Not Found");
+
+        response.set_reason_phrase(StatusCode::NotAcceptable.to_string());
+        assert_eq!(response.reason_phrase(), "Not Acceptable");
+    }
+
+    #[test]
+    fn test_into_forwarded() {
+        let via = headers::ViaEntry::new(Version::V2_0, "TCP", "proxy.example.com");
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(headers::MAX_FORWARDS, "2")
+            .header(headers::PROXY_REQUIRE, "play.basic")
+            .header(headers::PROXY_AUTHORIZATION, "Basic dXNlcjpwYXNz")
+            .empty()
+            .into_forwarded(via.clone())
+            .unwrap();
+
+        assert_eq!(request.header(&headers::MAX_FORWARDS).unwrap(), "1");
+        assert_eq!(request.header(&headers::PROXY_REQUIRE), None);
+        assert_eq!(request.header(&headers::PROXY_AUTHORIZATION), None);
+        assert_eq!(
+            request.typed_header::<headers::Via>().unwrap(),
+            Some(headers::Via::from(vec![via.clone()]))
+        );
+
+        let request = request.into_forwarded(via.clone()).unwrap();
+        assert_eq!(request.header(&headers::MAX_FORWARDS).unwrap(), "0");
+        assert_eq!(
+            request.into_forwarded(via.clone()),
+            Err(ForwardError::MaxForwardsExceeded)
+        );
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(headers::MAX_FORWARDS, "-1")
+            .empty();
+        assert_eq!(
+            request.into_forwarded(via.clone()),
+            Err(ForwardError::InvalidMaxForwards)
+        );
+
+        let mut request = Request::builder(Method::Options, Version::V2_0).empty();
+        assert_eq!(request.decrement_max_forwards(), Ok(()));
+        assert_eq!(request.header(&headers::MAX_FORWARDS), None);
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let data = &b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n"[..];
+
+        let request = Request::<Vec<u8>>::try_from(data).unwrap();
+        assert_eq!(request.method(), Method::Options);
+        assert!(Response::<Vec<u8>>::try_from(data).is_err());
+
+        let mut pipelined = Vec::from(data);
+        pipelined.extend_from_slice(data);
+        assert!(Request::<Vec<u8>>::try_from(&pipelined[..]).is_err());
+        let (_, consumed) = Request::<Vec<u8>>::parse(&pipelined).unwrap();
+        assert_eq!(consumed, data.len());
+
+        let data = &b"RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n"[..];
+        let response = Response::<Vec<u8>>::try_from(data).unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert!(Request::<Vec<u8>>::try_from(data).is_err());
+
+        let request =
+            Request::<Vec<u8>>::try_from("OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n").unwrap();
+        assert_eq!(request.method(), Method::Options);
+        let response = Response::<Vec<u8>>::try_from("RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n").unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+    }
+
+    #[test]
+    fn test_message_diff() {
+        let request = Message::from(
+            Request::builder(Method::Options, Version::V2_0)
+                .header(headers::CSEQ, "1")
+                .header(headers::USER_AGENT, "test")
+                .build(Vec::from(&b"abc"[..])),
+        );
+        let response = Message::from(
+            Response::builder(Version::V2_0, StatusCode::Ok)
+                .header(headers::CSEQ, "1")
+                .header(headers::SERVER, "test")
+                .build(Vec::from(&b"ab"[..])),
+        );
+
+        assert_eq!(request.diff(&request), vec![]);
+        assert_eq!(
+            request.diff(&response),
+            vec![
+                MessageDiff::StartLine {
+                    old: String::from("OPTIONS * RTSP/2.0"),
+                    new: String::from("RTSP/2.0 200 Ok"),
+                },
+                MessageDiff::HeaderChanged {
+                    name: headers::CONTENT_LENGTH,
+                    old: "3".into(),
+                    new: "2".into(),
+                },
+                MessageDiff::HeaderRemoved {
+                    name: headers::USER_AGENT,
+                    value: "test".into(),
+                },
+                MessageDiff::HeaderAdded {
+                    name: headers::SERVER,
+                    value: "test".into(),
+                },
+                MessageDiff::BodyLength { old: 3, new: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_debug_body() {
+        let response = Response::builder(Version::V2_0, StatusCode::Ok).build(vec![b'a'; 250]);
+
+        let debug = format!("{:?}", response);
+        assert!(debug.ends_with(&format!(
+            "body: b\"{}\" [... 50 more bytes] }}",
+            "a".repeat(200)
+        )));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok).build(vec![b'a'; 200]);
+        assert!(format!("{:?}", response).ends_with(&format!("body: b\"{}\" }}", "a".repeat(200))));
+
+        let data = Data::new(0, &b"\x00\r\n"[..]);
+        assert_eq!(
+            format!("{:?}", Message::from(data)),
+            "Data(Data { channel_id: 0, body: b\"\\x00\\r\\n\" })"
+        );
+
+        let request = Request::builder(Method::Options, Version::V2_0).empty();
+        assert!(format!("{:?}", request).ends_with("body: b\"\" }"));
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// Buffering parser for a stream of RTSP messages.
+///
+/// Data is passed to the parser with [`push`](#method.push) as it arrives, e.g. from a TCP
+/// connection, and complete messages are returned by [`parse`](#method.parse) once enough data
+/// is available.
+///
+/// The parser can be cloned to fork its state at any point, e.g. for speculatively parsing the
+/// buffered data without consuming it.
+///
+/// ```rust
+/// let mut parser = rtsp_types::MessageParser::new();
+///
+/// parser.push(b"OPTIONS * RTSP/2.0\r\n");
+/// assert!(parser.parse().expect("Failed to parse data").is_none());
+///
+/// parser.push(b"CSeq: 1\r\n\r\n");
+/// match parser.parse().expect("Failed to parse data") {
+///     Some(rtsp_types::Message::Request(ref request)) => {
+///         assert_eq!(request.method(), rtsp_types::Method::Options);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageParser {
+    buffer: Vec<u8>,
+}
+
+impl MessageParser {
+    /// Creates a new parser without any buffered data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all buffered data.
+    ///
+    /// The allocation of the internal buffer is kept so that the parser can be reused for another
+    /// connection without re-allocating.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the number of bytes currently buffered.
+    ///
+    /// This can be used for applying backpressure, e.g. by not reading more data from the
+    /// connection while too much data is buffered.
+    pub fn buffer_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Appends data to the end of the internal buffer.
+    pub fn push(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Try parse the next message from the buffered data.
+    ///
+    /// Returns `Ok(None)` if more data is needed for a complete message. On success the message
+    /// is removed from the buffered data.
+    ///
+    /// If parsing fails the buffered data is kept unchanged.
+    pub fn parse(&mut self) -> Result<Option<Message<Vec<u8>>>, ParseError> {
+        match Message::parse(&self.buffer) {
+            Ok((message, consumed)) => {
+                self.buffer.drain(..consumed);
+                Ok(Some(message))
+            }
+            Err(ParseError::Incomplete(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Try parse the next message from the buffered data after the connection was closed.
+    ///
+    /// This behaves the same as [`parse`](#method.parse) but uses
+    /// [`Message::parse_at_eof`](enum.Message.html#method.parse_at_eof), i.e. an RTSP 1.0
+    /// response with a `Connection: close` header and without `Content-Length` header takes all
+    /// remaining buffered data as its body.
+    ///
+    /// Returns `Ok(None)` if no data is buffered. As no more data can arrive, incomplete
+    /// messages are returned as [`ParseError::Incomplete`](enum.ParseError.html#variant.Incomplete).
+    ///
+    /// ```rust
+    /// let mut parser = rtsp_types::MessageParser::new();
+    ///
+    /// parser.push(b"RTSP/1.0 200 OK\r\nCSeq: 1\r\nConnection: close\r\n\r\nv=0\r\n");
+    /// match parser.parse_eof().expect("Failed to parse data") {
+    ///     Some(rtsp_types::Message::Response(ref response)) => {
+    ///         assert_eq!(response.body(), b"v=0\r\n");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(parser.parse_eof().expect("Failed to parse data").is_none());
+    /// ```
+    pub fn parse_eof(&mut self) -> Result<Option<Message<Vec<u8>>>, ParseError> {
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let (message, consumed) = Message::parse_at_eof(&self.buffer)?;
+        self.buffer.drain(..consumed);
+        Ok(Some(message))
+    }
+
+    /// Discards buffered data up to the start of the next message.
+    ///
+    /// This can be used for recovering after [`parse`](#method.parse) failed with
+    /// [`ParseError::Error`](enum.ParseError.html#variant.Error). The data is skipped up to the
+    /// next line that looks like the start of a request, response or data message. If no such
+    /// line is found all buffered data is discarded.
+    ///
+    /// Returns the number of bytes that were discarded.
+    ///
+    /// ```rust
+    /// let mut parser = rtsp_types::MessageParser::new();
+    ///
+    /// parser.push(b"GARBAGE\r\nOPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n");
+    /// assert!(parser.parse().is_err());
+    ///
+    /// assert_eq!(parser.discard_to_next_message(), 9);
+    /// assert!(matches!(
+    ///     parser.parse(),
+    ///     Ok(Some(rtsp_types::Message::Request(_)))
+    /// ));
+    /// ```
+    pub fn discard_to_next_message(&mut self) -> usize {
+        let discard = self
+            .buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(idx, _)| idx + 1)
+            .find(|idx| is_message_start(&self.buffer[*idx..]))
+            .unwrap_or(self.buffer.len());
+
+        self.buffer.drain(..discard);
+        discard
+    }
+}
+
+/// Checks if `data` could be the start of a message.
+///
+/// `data` is assumed to start at the beginning of a line and might contain only the beginning of
+/// the message.
+fn is_message_start(data: &[u8]) -> bool {
+    const VERSION_PREFIX: &[u8] = b"RTSP/";
+
+    // Response status line or the beginning of it
+    if data.len() < VERSION_PREFIX.len() && VERSION_PREFIX.starts_with(data) {
+        return true;
+    }
+    if data.starts_with(VERSION_PREFIX) {
+        return true;
+    }
+
+    // Interleaved data
+    if data.first() == Some(&b'$') {
+        return true;
+    }
+
+    // Request line: method token, a space and, if the line is complete, the version at the end
+    let method_len = data
+        .iter()
+        .take_while(|b| crate::parser::is_token_char(**b))
+        .count();
+    if method_len == 0 || data.get(method_len) != Some(&b' ') {
+        return false;
+    }
+
+    match data.iter().position(|b| *b == b'\n') {
+        None => true,
+        Some(end) => {
+            let line = &data[..end];
+            line.windows(6).any(|w| w == b" RTSP/")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_parser() {
+        let data = b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\nRTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n";
+
+        let mut parser = MessageParser::new();
+        for chunk in data.chunks(7) {
+            parser.push(chunk);
+        }
+        assert_eq!(parser.buffer_len(), data.len());
+
+        let mut fork = parser.clone();
+        assert!(matches!(fork.parse(), Ok(Some(Message::Request(_)))));
+        assert!(matches!(fork.parse(), Ok(Some(Message::Response(_)))));
+        assert!(matches!(fork.parse(), Ok(None)));
+
+        assert!(matches!(parser.parse(), Ok(Some(Message::Request(_)))));
+        assert_eq!(parser.buffer_len(), 28);
+        assert!(matches!(parser.parse(), Ok(Some(Message::Response(_)))));
+        assert!(matches!(parser.parse(), Ok(None)));
+        assert_eq!(parser.buffer_len(), 0);
+
+        parser.push(&data[..10]);
+        parser.reset();
+        parser.push(data);
+        assert!(matches!(parser.parse(), Ok(Some(Message::Request(_)))));
+    }
+
+    #[test]
+    fn test_parse_eof() {
+        let data = b"RTSP/1.0 200 OK\r\nCSeq: 1\r\nConnection: close\r\n\r\nv=0\r\n";
+
+        let mut parser = MessageParser::new();
+        parser.push(data);
+
+        // Without EOF the body is not delimited by the connection close
+        let mut fork = parser.clone();
+        match fork.parse() {
+            Ok(Some(Message::Response(response))) => assert_eq!(response.body(), b""),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(fork.buffer_len(), 5);
+
+        match parser.parse_eof() {
+            Ok(Some(Message::Response(response))) => assert_eq!(response.body(), b"v=0\r\n"),
+            res => panic!("unexpected result {:?}", res),
+        }
+        assert_eq!(parser.buffer_len(), 0);
+        assert!(matches!(parser.parse_eof(), Ok(None)));
+
+        parser.push(b"RTSP/1.0 200 OK\r\nCSeq: 1\r\n");
+        assert!(matches!(parser.parse_eof(), Err(ParseError::Incomplete(_))));
+        assert_eq!(parser.buffer_len(), 26);
+    }
+
+    #[test]
+    fn test_discard_to_next_message() {
+        let mut parser = MessageParser::new();
+        parser.push(b"FOO BAR\r\n\r\nRTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n");
+        assert!(parser.parse().is_err());
+        assert_eq!(parser.discard_to_next_message(), 11);
+        assert!(matches!(parser.parse(), Ok(Some(Message::Response(_)))));
+
+        parser.push(b"FOO\r\nRT");
+        assert_eq!(parser.discard_to_next_message(), 5);
+        assert_eq!(parser.buffer_len(), 2);
+
+        parser.reset();
+        parser.push(b"FOO\r\n$\x00\x00\x01\x00");
+        assert_eq!(parser.discard_to_next_message(), 5);
+        assert!(matches!(parser.parse(), Ok(Some(Message::Data(_)))));
+
+        parser.push(b"GARBAGE");
+        assert_eq!(parser.discard_to_next_message(), 7);
+        assert_eq!(parser.buffer_len(), 0);
+    }
+}
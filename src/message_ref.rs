@@ -25,7 +25,18 @@ impl<'a> MessageRef<'a> {
     }
 
     pub fn parse(buf: &'a [u8]) -> Result<(Self, usize), ParseError> {
-        let (remainder, res) = match parser::message(buf) {
+        Self::parse_with(buf, parser::message)
+    }
+
+    pub fn parse_at_eof(buf: &'a [u8]) -> Result<(Self, usize), ParseError> {
+        Self::parse_with(buf, parser::message_at_eof)
+    }
+
+    fn parse_with(
+        buf: &'a [u8],
+        parser: fn(&'a [u8]) -> nom::IResult<&'a [u8], MessageRef<'a>>,
+    ) -> Result<(Self, usize), ParseError> {
+        let (remainder, res) = match parser(buf) {
             Ok(res) => res,
             Err(nom::Err::Incomplete(needed)) => {
                 return Err(ParseError::Incomplete(match needed {
@@ -160,6 +171,7 @@ impl<'a> RequestRef<'a> {
             version: self.version,
             headers: Headers::from_headers_ref(&self.headers),
             body: self.body.into(),
+            wire: WireCache::default(),
         })
     }
 
@@ -204,7 +216,7 @@ impl<'a> RequestRef<'a> {
     }
 
     #[allow(dead_code)]
-    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef> {
+    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef<'_>> {
         self.headers.iter()
     }
 }
@@ -233,6 +245,7 @@ impl<'a> ResponseRef<'a> {
             reason_phrase: self.reason_phrase.into(),
             headers: Headers::from_headers_ref(&self.headers),
             body: self.body.into(),
+            wire: WireCache::default(),
         }
     }
 
@@ -277,7 +290,7 @@ impl<'a> ResponseRef<'a> {
     }
 
     #[allow(dead_code)]
-    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef> {
+    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef<'_>> {
         self.headers.iter()
     }
 }
@@ -16,11 +16,11 @@ use nom::{Err, IResult, Needed};
 use std::str;
 use tinyvec::TinyVec;
 
-fn token(input: &[u8]) -> IResult<&[u8], &[u8]> {
-    fn is_token_char(i: u8) -> bool {
-        is_alphanumeric(i) || b"!#$%&'*+-.^_`|~".contains(&i)
-    }
+pub(crate) fn is_token_char(i: u8) -> bool {
+    is_alphanumeric(i) || b"!#$%&'*+-.^_`|~".contains(&i)
+}
 
+fn token(input: &[u8]) -> IResult<&[u8], &[u8]> {
     take_while(is_token_char)(input)
 }
 
@@ -57,7 +57,7 @@ fn rtsp_version(input: &[u8]) -> IResult<&[u8], Version> {
     )(input)
 }
 
-fn request_line(input: &[u8]) -> IResult<&[u8], RequestLine> {
+fn request_line(input: &[u8]) -> IResult<&[u8], RequestLine<'_>> {
     map(
         tuple((
             map(map_res(token, str::from_utf8), MethodRef::from),
@@ -82,7 +82,7 @@ fn from_digit(input: &str) -> Result<u16, std::num::ParseIntError> {
     str::parse::<u16>(input)
 }
 
-fn status_line(input: &[u8]) -> IResult<&[u8], StatusLine> {
+fn status_line(input: &[u8]) -> IResult<&[u8], StatusLine<'_>> {
     map(
         tuple((
             rtsp_version,
@@ -152,7 +152,7 @@ fn header_value(i: &[u8]) -> IResult<&[u8], &[u8]> {
     Err(Err::Incomplete(Needed::Unknown))
 }
 
-fn message_header(input: &[u8]) -> IResult<&[u8], HeaderRef> {
+fn message_header(input: &[u8]) -> IResult<&[u8], HeaderRef<'_>> {
     map(
         tuple((
             map_res(token, str::from_utf8),
@@ -165,32 +165,84 @@ fn message_header(input: &[u8]) -> IResult<&[u8], HeaderRef> {
     )(input)
 }
 
-fn headers(input: &[u8]) -> IResult<&[u8], TinyVec<[HeaderRef; 16]>> {
+fn headers(input: &[u8]) -> IResult<&[u8], TinyVec<[HeaderRef<'_>; 16]>> {
     terminated(many0_tinyvec(message_header), crlf)(input)
 }
 
+/// How the end of a message body is determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BodyFraming {
+    /// The message has no body.
+    None,
+    /// The body length is given by the `Content-Length` header.
+    ContentLength(usize),
+    /// The body extends until the connection is closed.
+    ///
+    /// Some RTSP 1.0 servers omit `Content-Length` from responses and signal the end of the body
+    /// by closing the connection, which they announce with `Connection: close`. This is only used
+    /// if the input is known to end where the connection was closed, otherwise a missing
+    /// `Content-Length` always means that there is no body.
+    UntilClose,
+}
+
+fn header_value_by_name<'a>(headers: &[HeaderRef<'a>], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| h.value)
+}
+
 fn content_length<'a>(
     headers: &[HeaderRef<'a>],
-) -> Result<usize, nom::Err<nom::error::Error<&'a [u8]>>> {
-    if let Some(h) = headers
-        .iter()
-        .find(|h| &h.name.to_ascii_uppercase() == "CONTENT-LENGTH")
-    {
-        return str::parse::<usize>(h.value).map_err(|_| {
+) -> Result<Option<usize>, nom::Err<nom::error::Error<&'a [u8]>>> {
+    if let Some(value) = header_value_by_name(headers, "Content-Length") {
+        return str::parse::<usize>(value).map(Some).map_err(|_| {
             nom::Err::Failure(nom::error::Error::new(
-                h.value.as_bytes(),
+                value.as_bytes(),
                 nom::error::ErrorKind::MapRes,
             ))
         });
     }
-    Ok(0)
+    Ok(None)
+}
+
+fn body_framing<'a>(
+    headers: &[HeaderRef<'a>],
+    allow_until_close: bool,
+) -> Result<BodyFraming, nom::Err<nom::error::Error<&'a [u8]>>> {
+    if let Some(content_length) = content_length(headers)? {
+        return Ok(BodyFraming::ContentLength(content_length));
+    }
+
+    let connection_close = header_value_by_name(headers, "Connection")
+        .map(|value| {
+            value
+                .split(',')
+                .any(|token| token.trim().eq_ignore_ascii_case("close"))
+        })
+        .unwrap_or(false);
+
+    if allow_until_close && connection_close {
+        Ok(BodyFraming::UntilClose)
+    } else {
+        Ok(BodyFraming::None)
+    }
+}
+
+fn body(input: &[u8], framing: BodyFraming) -> IResult<&[u8], &[u8]> {
+    match framing {
+        BodyFraming::None => Ok((input, &[])),
+        BodyFraming::ContentLength(len) => take(len)(input),
+        BodyFraming::UntilClose => Ok((&[], input)),
+    }
 }
 
-fn request(input: &[u8]) -> IResult<&[u8], RequestRef> {
+fn request(input: &[u8]) -> IResult<&[u8], RequestRef<'_>> {
     let (input, request_line) = request_line(input)?;
     let (input, headers) = headers(input)?;
-    let content_length = content_length(&headers)?;
-    let (input, body) = take(content_length)(input)?;
+    // Requests without Content-Length never have a body
+    let framing = body_framing(&headers, false)?;
+    let (input, body) = body(input, framing)?;
 
     Ok((
         input,
@@ -204,11 +256,16 @@ fn request(input: &[u8]) -> IResult<&[u8], RequestRef> {
     ))
 }
 
-fn response(input: &[u8]) -> IResult<&[u8], ResponseRef> {
+fn response(input: &[u8]) -> IResult<&[u8], ResponseRef<'_>> {
+    response_with_eof(input, false)
+}
+
+fn response_with_eof(input: &[u8], at_eof: bool) -> IResult<&[u8], ResponseRef<'_>> {
     let (input, status_line) = status_line(input)?;
     let (input, headers) = headers(input)?;
-    let content_length = content_length(&headers)?;
-    let (input, body) = take(content_length)(input)?;
+    // RTSP 2.0 requires Content-Length for all bodies
+    let framing = body_framing(&headers, at_eof && status_line.version == Version::V1_0)?;
+    let (input, body) = body(input, framing)?;
 
     Ok((
         input,
@@ -222,7 +279,7 @@ fn response(input: &[u8]) -> IResult<&[u8], ResponseRef> {
     ))
 }
 
-fn data(input: &[u8]) -> IResult<&[u8], DataRef> {
+fn data(input: &[u8]) -> IResult<&[u8], DataRef<'_>> {
     map(
         tuple((char('$'), take(1usize), flat_map(be_u16, take))),
         |(_, channel_id, body): (_, &[u8], _)| DataRef {
@@ -232,7 +289,19 @@ fn data(input: &[u8]) -> IResult<&[u8], DataRef> {
     )(input)
 }
 
-pub(crate) fn message(input: &[u8]) -> IResult<&[u8], MessageRef> {
+pub(crate) fn message(input: &[u8]) -> IResult<&[u8], MessageRef<'_>> {
+    message_with(input, response)
+}
+
+// Same as `message()` but the input is known to end where the connection was closed.
+pub(crate) fn message_at_eof(input: &[u8]) -> IResult<&[u8], MessageRef<'_>> {
+    message_with(input, |input| response_with_eof(input, true))
+}
+
+fn message_with(
+    input: &[u8],
+    response: fn(&[u8]) -> IResult<&[u8], ResponseRef<'_>>,
+) -> IResult<&[u8], MessageRef<'_>> {
     flat_map(fold_many0(crlf, || (), |_acc, _item| ()), |_| {
         alt((
             map(data, MessageRef::Data),
@@ -598,6 +667,59 @@ REMAINDER"
         ));
     }
 
+    #[test]
+    fn test_body_until_close() {
+        let data = b"RTSP/1.0 200 OK\r\n\
+CSeq: 1\r\n\
+Connection: close\r\n\
+\r\n\
+0123456789";
+
+        let (rem, res) = response(data).unwrap();
+        assert_eq!(rem, b"0123456789");
+        assert!(res.body.is_empty());
+
+        let (rem, res) = response_with_eof(data, true).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(res.body, b"0123456789");
+
+        // Body-less responses are complete without waiting for the connection to close
+        let (rem, res) = response(
+            b"RTSP/1.0 200 OK\r\n\
+CSeq: 1\r\n\
+Connection: close\r\n\
+\r\n",
+        )
+        .unwrap();
+        assert!(rem.is_empty());
+        assert!(res.body.is_empty());
+
+        // RTSP 2.0 requires Content-Length for all bodies
+        let (rem, res) = response_with_eof(
+            b"RTSP/2.0 200 OK\r\n\
+CSeq: 1\r\n\
+Connection: close\r\n\
+\r\n\
+REMAINDER",
+            true,
+        )
+        .unwrap();
+        assert_eq!(rem, b"REMAINDER");
+        assert!(res.body.is_empty());
+
+        // Requests without Content-Length never have a body
+        let (rem, res) = request(
+            b"OPTIONS * RTSP/1.0\r\n\
+CSeq: 1\r\n\
+Connection: close\r\n\
+\r\n\
+REMAINDER",
+        )
+        .unwrap();
+        assert_eq!(rem, b"REMAINDER");
+        assert!(res.body.is_empty());
+    }
+
     #[test]
     fn test_data() {
         assert_eq!(
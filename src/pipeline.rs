@@ -0,0 +1,231 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::collections::BTreeSet;
+
+/// Validator for the sequence numbers of pipelined requests.
+///
+/// Requests are added one after another with [`push`](#method.push) and all problems that were
+/// found are returned by [`finish`](#method.finish). The following is checked:
+///
+/// * all requests have a valid `CSeq` header,
+/// * the `CSeq` values are strictly increasing and contain no duplicates,
+/// * all requests have the same `Pipelined-Requests` header, or none of them has one.
+///
+/// See [RFC 7826 section 12](https://tools.ietf.org/html/rfc7826#section-12) for details about
+/// pipelining.
+///
+/// ```rust
+/// let requests = [1, 3, 2].iter().map(|cseq| {
+///     rtsp_types::Request::builder(rtsp_types::Method::Options, rtsp_types::Version::V2_0)
+///         .typed_header(&rtsp_types::headers::CSeq::from(*cseq))
+///         .empty()
+/// })
+/// .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     rtsp_types::PipelineValidator::validate(&requests),
+///     vec![rtsp_types::PipelineError::NotIncreasing {
+///         index: 2,
+///         previous: 3,
+///         cseq: 2,
+///     }],
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PipelineValidator {
+    index: usize,
+    last_cseq: Option<u32>,
+    seen: BTreeSet<u32>,
+    pipeline: Option<Option<u32>>,
+    errors: Vec<PipelineError>,
+}
+
+impl PipelineValidator {
+    /// Creates a new validator for an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates all requests of a pipeline in order.
+    pub fn validate<'a, Body: 'a>(
+        requests: impl IntoIterator<Item = &'a Request<Body>>,
+    ) -> Vec<PipelineError> {
+        let mut validator = Self::new();
+        for request in requests {
+            validator.push(request);
+        }
+
+        validator.finish()
+    }
+
+    /// Adds the next request of the pipeline.
+    pub fn push<Body>(&mut self, request: &Request<Body>) {
+        let index = self.index;
+        self.index += 1;
+
+        match request.typed_header::<headers::CSeq>() {
+            Ok(Some(cseq)) => {
+                let cseq = *cseq;
+
+                if !self.seen.insert(cseq) {
+                    self.errors
+                        .push(PipelineError::DuplicateCSeq { index, cseq });
+                } else if let Some(previous) = self.last_cseq.filter(|previous| *previous >= cseq) {
+                    self.errors.push(PipelineError::NotIncreasing {
+                        index,
+                        previous,
+                        cseq,
+                    });
+                }
+
+                self.last_cseq = Some(cseq);
+            }
+            _ => self.errors.push(PipelineError::MissingCSeq { index }),
+        }
+
+        let pipeline = match request.typed_header::<headers::PipelinedRequests>() {
+            Ok(pipeline) => pipeline.map(|pipeline| *pipeline),
+            Err(_) => {
+                self.errors
+                    .push(PipelineError::InvalidPipelinedRequests { index });
+                return;
+            }
+        };
+
+        match self.pipeline {
+            None => self.pipeline = Some(pipeline),
+            Some(expected) if expected != pipeline => {
+                self.errors.push(PipelineError::PipelineMismatch {
+                    index,
+                    expected,
+                    pipeline,
+                });
+            }
+            Some(_) => (),
+        }
+    }
+
+    /// Returns all problems found in the pipeline.
+    pub fn finish(self) -> Vec<PipelineError> {
+        self.errors
+    }
+}
+
+/// Problem found by the [`PipelineValidator`](struct.PipelineValidator.html).
+///
+/// `index` is the position of the affected request in the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// The request has no valid `CSeq` header.
+    MissingCSeq { index: usize },
+    /// The `CSeq` was already used by a previous request.
+    DuplicateCSeq { index: usize, cseq: u32 },
+    /// The `CSeq` is not bigger than the one of the previous request.
+    NotIncreasing {
+        index: usize,
+        previous: u32,
+        cseq: u32,
+    },
+    /// The request has an invalid `Pipelined-Requests` header.
+    InvalidPipelinedRequests { index: usize },
+    /// The `Pipelined-Requests` header is different from the first request.
+    PipelineMismatch {
+        index: usize,
+        expected: Option<u32>,
+        pipeline: Option<u32>,
+    },
+}
+
+impl std::error::Error for PipelineError {}
+
+impl std::fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            PipelineError::MissingCSeq { index } => {
+                write!(f, "Request {index} has no valid CSeq")
+            }
+            PipelineError::DuplicateCSeq { index, cseq } => {
+                write!(f, "Request {index} has duplicate CSeq {cseq}")
+            }
+            PipelineError::NotIncreasing {
+                index,
+                previous,
+                cseq,
+            } => write!(
+                f,
+                "Request {index} has CSeq {cseq} not bigger than previous CSeq {previous}"
+            ),
+            PipelineError::InvalidPipelinedRequests { index } => {
+                write!(f, "Request {index} has invalid Pipelined-Requests header")
+            }
+            PipelineError::PipelineMismatch {
+                index,
+                expected,
+                pipeline,
+            } => write!(
+                f,
+                "Request {index} has Pipelined-Requests {pipeline:?} instead of {expected:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(cseq: Option<u32>, pipeline: Option<u32>) -> Request<Empty> {
+        let mut builder = Request::builder(Method::Options, Version::V2_0);
+        if let Some(cseq) = cseq {
+            builder = builder.typed_header(&headers::CSeq::from(cseq));
+        }
+        if let Some(pipeline) = pipeline {
+            builder = builder.typed_header(&headers::PipelinedRequests::from(pipeline));
+        }
+
+        builder.empty()
+    }
+
+    #[test]
+    fn test_pipeline_validator() {
+        let requests = [
+            request(Some(1), Some(7)),
+            request(Some(2), Some(7)),
+            request(Some(3), Some(7)),
+        ];
+        assert_eq!(PipelineValidator::validate(&requests), vec![]);
+
+        let requests = [
+            request(Some(1), Some(7)),
+            request(None, Some(7)),
+            request(Some(1), Some(8)),
+            request(Some(0), None),
+        ];
+        assert_eq!(
+            PipelineValidator::validate(&requests),
+            vec![
+                PipelineError::MissingCSeq { index: 1 },
+                PipelineError::DuplicateCSeq { index: 2, cseq: 1 },
+                PipelineError::PipelineMismatch {
+                    index: 2,
+                    expected: Some(7),
+                    pipeline: Some(8),
+                },
+                PipelineError::NotIncreasing {
+                    index: 3,
+                    previous: 1,
+                    cseq: 0,
+                },
+                PipelineError::PipelineMismatch {
+                    index: 3,
+                    expected: Some(7),
+                    pipeline: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,508 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! RTSP URL helpers.
+//!
+//! [`resolve_async`](fn.resolve_async.html) is a free function taking a `&Url` instead of a
+//! method of [`RtspUrlExt`](trait.RtspUrlExt.html) because async functions in traits need Rust
+//! 1.75, which is newer than the minimum supported Rust version of this crate.
+
+use super::*;
+
+/// URL scheme of an RTSP URL.
+///
+/// See [RFC 7826 section 19.2](https://tools.ietf.org/html/rfc7826#section-19.2).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RtspScheme {
+    /// `rtsp`: RTSP over TCP
+    Rtsp,
+    /// `rtsps`: RTSP over TLS
+    Rtsps,
+}
+
+impl RtspScheme {
+    /// Get the string representation of the scheme, e.g. `rtsps`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RtspScheme::Rtsp => "rtsp",
+            RtspScheme::Rtsps => "rtsps",
+        }
+    }
+
+    /// Get the default port for this scheme.
+    ///
+    /// This is 554 for `rtsp` and 322 for `rtsps`.
+    pub fn default_port(&self) -> u16 {
+        match self {
+            RtspScheme::Rtsp => 554,
+            RtspScheme::Rtsps => 322,
+        }
+    }
+
+    /// Returns `true` if connections for this scheme use TLS.
+    pub fn is_secure(&self) -> bool {
+        *self == RtspScheme::Rtsps
+    }
+
+    /// Get the scheme of the URL or `None` if it's not an RTSP URL.
+    ///
+    /// ```rust
+    /// let url = rtsp_types::Url::parse("rtsps://example.com/test").expect("Invalid URI");
+    /// assert_eq!(
+    ///     rtsp_types::RtspScheme::from_url(&url),
+    ///     Some(rtsp_types::RtspScheme::Rtsps),
+    /// );
+    ///
+    /// let url = rtsp_types::Url::parse("http://example.com/test").expect("Invalid URI");
+    /// assert_eq!(rtsp_types::RtspScheme::from_url(&url), None);
+    /// ```
+    pub fn from_url(url: &Url) -> Option<RtspScheme> {
+        match url.scheme() {
+            "rtsp" => Some(RtspScheme::Rtsp),
+            "rtsps" => Some(RtspScheme::Rtsps),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RtspScheme {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// Request URI of a [`Request`](struct.Request.html).
+///
+/// Requests that apply to the server as a whole instead of a specific resource use `*` as
+/// request URI, see [RFC 7826 section 7.1.2](https://tools.ietf.org/html/rfc7826#section-7.1.2).
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RequestUrl<'a> {
+    /// `*`
+    Wildcard,
+    /// URL of a specific resource
+    Url(&'a Url),
+}
+
+impl<'a> RequestUrl<'a> {
+    /// Get the string representation of the request URI.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            RequestUrl::Wildcard => "*",
+            RequestUrl::Url(url) => url.as_str(),
+        }
+    }
+
+    /// Get the URL or `None` for `*`.
+    pub fn as_url(&self) -> Option<&'a Url> {
+        match self {
+            RequestUrl::Wildcard => None,
+            RequestUrl::Url(url) => Some(url),
+        }
+    }
+}
+
+/// Compares with a request URI string.
+///
+/// The string is parsed and compared structurally, i.e. the host is compared case-insensitively
+/// and the scheme's default port is the same as no port. Strings that are not `*` or a valid RTSP
+/// URL are never equal.
+///
+/// ```rust
+/// let url = rtsp_types::Url::parse("rtsp://example.com/live").expect("Invalid URI");
+/// let request_url = rtsp_types::RequestUrl::Url(&url);
+///
+/// assert!(request_url == "RTSP://EXAMPLE.com/live");
+/// assert!(request_url != "not a URL");
+/// assert!(rtsp_types::RequestUrl::Wildcard == "*");
+/// ```
+impl<'a> PartialEq<str> for RequestUrl<'a> {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            RequestUrl::Wildcard => other == "*",
+            RequestUrl::Url(url) => match Url::parse(other) {
+                Ok(ref other) => RtspScheme::from_url(other).is_some() && rtsp_urls_eq(url, other),
+                Err(_) => false,
+            },
+        }
+    }
+}
+
+/// Compares two URLs while treating the host case-insensitively and default ports the same as
+/// no port.
+fn rtsp_urls_eq(url: &Url, other: &Url) -> bool {
+    url.scheme() == other.scheme()
+        && url.username() == other.username()
+        && url.password() == other.password()
+        && match (url.host_str(), other.host_str()) {
+            (Some(host), Some(other_host)) => host.eq_ignore_ascii_case(other_host),
+            (host, other_host) => host == other_host,
+        }
+        && url.port_or_default() == other.port_or_default()
+        && url.path() == other.path()
+        && url.query() == other.query()
+        && url.fragment() == other.fragment()
+}
+
+impl<'a> PartialEq<&str> for RequestUrl<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a> PartialEq<String> for RequestUrl<'a> {
+    fn eq(&self, other: &String) -> bool {
+        *self == *other.as_str()
+    }
+}
+
+impl<'a> PartialEq<RequestUrl<'a>> for str {
+    fn eq(&self, other: &RequestUrl<'a>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a> PartialEq<RequestUrl<'a>> for &str {
+    fn eq(&self, other: &RequestUrl<'a>) -> bool {
+        *other == **self
+    }
+}
+
+impl<'a> PartialEq<RequestUrl<'a>> for String {
+    fn eq(&self, other: &RequestUrl<'a>) -> bool {
+        *other == *self.as_str()
+    }
+}
+
+impl<'a> fmt::Display for RequestUrl<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+/// RTSP specific extensions for [`Url`](struct.Url.html).
+pub trait RtspUrlExt {
+    /// Get the RTSP scheme of the URL or `None` if it's not an RTSP URL.
+    fn rtsp_scheme(&self) -> Option<RtspScheme>;
+
+    /// Get the port of the URL or the default port of its RTSP scheme.
+    ///
+    /// `Url::port()` only returns explicitly specified ports. This returns `None` if no port is
+    /// specified and the URL is not an RTSP URL.
+    ///
+    /// ```rust
+    /// use rtsp_types::RtspUrlExt;
+    ///
+    /// let url = rtsp_types::Url::parse("rtsps://example.com/test").expect("Invalid URI");
+    /// assert_eq!(url.port(), None);
+    /// assert_eq!(url.port_or_default(), Some(322));
+    /// ```
+    fn port_or_default(&self) -> Option<u16>;
+
+    /// Get the host of the URL followed by `:port` if the port is not the scheme's default port.
+    ///
+    /// Returns `None` if the URL has no host.
+    ///
+    /// ```rust
+    /// use rtsp_types::RtspUrlExt;
+    ///
+    /// let url = rtsp_types::Url::parse("rtsp://example.com:554/test").expect("Invalid URI");
+    /// assert_eq!(url.host_with_port().as_deref(), Some("example.com"));
+    ///
+    /// let url = rtsp_types::Url::parse("rtsp://[::1]:8554/test").expect("Invalid URI");
+    /// assert_eq!(url.host_with_port().as_deref(), Some("[::1]:8554"));
+    /// ```
+    fn host_with_port(&self) -> Option<String>;
+
+    /// Resolve the `a=control` attribute of a media description against this aggregate control
+    /// URL.
+    ///
+    /// This follows [RFC 7826 appendix C.1.1](https://tools.ietf.org/html/rfc7826#appendix-C.1.1):
+    /// `*` refers to the aggregate control URL itself, absolute URLs are used as is and relative
+    /// URLs are resolved according to [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5).
+    /// Note that the last path segment of the base URL is replaced unless it ends with `/`.
+    ///
+    /// ```rust
+    /// use rtsp_types::RtspUrlExt;
+    ///
+    /// let url = rtsp_types::Url::parse("rtsp://example.com/movie/").expect("Invalid URI");
+    /// assert_eq!(
+    ///     url.aggregate_control_url("trackID=1").unwrap().as_str(),
+    ///     "rtsp://example.com/movie/trackID=1",
+    /// );
+    /// ```
+    fn aggregate_control_url(&self, control: &str) -> Result<Url, url::ParseError>;
+
+    /// Compares two URLs while ignoring their query strings.
+    ///
+    /// ```rust
+    /// use rtsp_types::RtspUrlExt;
+    ///
+    /// let url = rtsp_types::Url::parse("rtsp://example.com/test?token=1").expect("Invalid URI");
+    /// let other = rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI");
+    /// assert!(url.eq_without_query(&other));
+    /// ```
+    fn eq_without_query(&self, other: &Url) -> bool;
+
+    /// Compares two URLs while ignoring their fragments.
+    fn eq_without_fragment(&self, other: &Url) -> bool;
+}
+
+impl RtspUrlExt for Url {
+    fn rtsp_scheme(&self) -> Option<RtspScheme> {
+        RtspScheme::from_url(self)
+    }
+
+    fn port_or_default(&self) -> Option<u16> {
+        self.port()
+            .or_else(|| self.rtsp_scheme().map(|scheme| scheme.default_port()))
+    }
+
+    fn host_with_port(&self) -> Option<String> {
+        let host = self.host_str()?;
+        let default_port = self.rtsp_scheme().map(|scheme| scheme.default_port());
+
+        match self.port() {
+            Some(port) if Some(port) != default_port => Some(format!("{host}:{port}")),
+            _ => Some(String::from(host)),
+        }
+    }
+
+    fn aggregate_control_url(&self, control: &str) -> Result<Url, url::ParseError> {
+        let control = control.trim();
+
+        if control == "*" {
+            return Ok(self.clone());
+        }
+
+        match Url::parse(control) {
+            Ok(url) => Ok(url),
+            Err(url::ParseError::RelativeUrlWithoutBase) => self.join(control),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn eq_without_query(&self, other: &Url) -> bool {
+        use url::Position;
+
+        self[..Position::AfterPath] == other[..Position::AfterPath]
+            && self.fragment() == other.fragment()
+    }
+
+    fn eq_without_fragment(&self, other: &Url) -> bool {
+        use url::Position;
+
+        self[..Position::AfterQuery] == other[..Position::AfterQuery]
+    }
+}
+
+/// Resolves the host of an RTSP URL to socket addresses.
+///
+/// The port of the URL is used, or the scheme's default port if none is specified. IP addresses
+/// are returned directly without any lookup.
+///
+/// This is only available with the `tokio` feature.
+///
+/// ```rust,no_run
+/// # async fn connect() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = rtsp_types::Url::parse("rtsp://example.com/test")?;
+/// let addrs = rtsp_types::resolve_async(&url).await?;
+/// let stream = tokio::net::TcpStream::connect(&addrs[..]).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn resolve_async(url: &Url) -> Result<Vec<std::net::SocketAddr>, ResolveError> {
+    use std::net::SocketAddr;
+
+    let port = url.port_or_default().ok_or(ResolveError::UnknownPort)?;
+
+    match url.host().ok_or(ResolveError::NoHost)? {
+        Host::Domain(domain) => Ok(tokio::net::lookup_host((domain, port))
+            .await
+            .map_err(ResolveError::IoError)?
+            .collect()),
+        Host::Ipv4(addr) => Ok(vec![SocketAddr::from((addr, port))]),
+        Host::Ipv6(addr) => Ok(vec![SocketAddr::from((addr, port))]),
+    }
+}
+
+/// Error returned by [`resolve_async`](fn.resolve_async.html).
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub enum ResolveError {
+    /// The URL has no host.
+    NoHost,
+    /// The URL has no port and is not an RTSP URL with a default port.
+    UnknownPort,
+    /// The lookup failed.
+    IoError(std::io::Error),
+}
+
+#[cfg(feature = "tokio")]
+impl std::error::Error for ResolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ResolveError::IoError(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            ResolveError::NoHost => write!(f, "URL has no host"),
+            ResolveError::UnknownPort => write!(f, "URL has no port"),
+            ResolveError::IoError(ref error) => write!(f, "Lookup IO error: {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_control_url() {
+        let cases = [
+            // Base with trailing slash
+            (
+                "rtsp://example.com/movie/",
+                "*",
+                "rtsp://example.com/movie/",
+            ),
+            (
+                "rtsp://example.com/movie/",
+                "track1",
+                "rtsp://example.com/movie/track1",
+            ),
+            (
+                "rtsp://example.com/movie/",
+                "/other/track1",
+                "rtsp://example.com/other/track1",
+            ),
+            (
+                "rtsp://example.com/movie/",
+                "../track1",
+                "rtsp://example.com/track1",
+            ),
+            (
+                "rtsp://example.com/movie/",
+                "rtsp://media.example.com/track1",
+                "rtsp://media.example.com/track1",
+            ),
+            // Base without trailing slash
+            ("rtsp://example.com/movie", "*", "rtsp://example.com/movie"),
+            (
+                "rtsp://example.com/movie",
+                "track1",
+                "rtsp://example.com/track1",
+            ),
+            (
+                "rtsp://example.com/movie",
+                "/other/track1",
+                "rtsp://example.com/other/track1",
+            ),
+            (
+                "rtsp://example.com/movie",
+                "rtsps://media.example.com:8322/track1",
+                "rtsps://media.example.com:8322/track1",
+            ),
+            // Base with port and query
+            (
+                "rtsp://example.com:8554/movie/?token=abc",
+                "track1",
+                "rtsp://example.com:8554/movie/track1",
+            ),
+            (
+                "rtsp://example.com:8554/movie/?token=abc",
+                "?track=1",
+                "rtsp://example.com:8554/movie/?track=1",
+            ),
+            (
+                "rtsp://example.com:8554/movie/?token=abc",
+                "//media.example.com/track1",
+                "rtsp://media.example.com/track1",
+            ),
+        ];
+
+        for (base, control, expected) in &cases {
+            let base = Url::parse(base).unwrap();
+            assert_eq!(
+                base.aggregate_control_url(control).unwrap().as_str(),
+                *expected,
+                "base {base} control {control}"
+            );
+        }
+
+        let base = Url::parse("rtsp://example.com/movie/").unwrap();
+        assert!(base.aggregate_control_url("rtsp://[::1").is_err());
+    }
+
+    #[test]
+    fn test_eq_without_query() {
+        let url = Url::parse("rtsp://example.com/test?token=1#frag").unwrap();
+
+        let other = Url::parse("rtsp://example.com/test#frag").unwrap();
+        assert!(url.eq_without_query(&other));
+        assert!(!url.eq_without_fragment(&other));
+
+        let other = Url::parse("rtsp://example.com/test?token=1").unwrap();
+        assert!(!url.eq_without_query(&other));
+        assert!(url.eq_without_fragment(&other));
+
+        let other = Url::parse("rtsp://example.com/other?token=1#frag").unwrap();
+        assert!(!url.eq_without_query(&other));
+        assert!(!url.eq_without_fragment(&other));
+    }
+
+    #[test]
+    fn test_request_url_eq_str() {
+        let url = Url::parse("rtsp://example.com:554/live").unwrap();
+        let request_url = RequestUrl::Url(&url);
+
+        assert_eq!(request_url, "rtsp://example.com:554/live");
+        assert_eq!("rtsp://EXAMPLE.com:554/live", request_url);
+        assert_eq!(request_url, String::from("rtsp://example.com:554/live"));
+        assert_eq!(String::from("rtsp://example.com:554/live"), request_url);
+        assert_eq!(request_url, "rtsp://example.com/live");
+        assert_ne!(request_url, "rtsp://example.com:8554/live");
+        assert_ne!(request_url, "rtsps://example.com:554/live");
+        assert_ne!(request_url, "*");
+        assert_ne!(request_url, "rtsp://[::1");
+
+        let url = Url::parse("http://example.com/live").unwrap();
+        assert_ne!(RequestUrl::Url(&url), "http://example.com/live");
+
+        assert_eq!(RequestUrl::Wildcard, "*");
+        assert_ne!(RequestUrl::Wildcard, "rtsp://example.com/live");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_resolve_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let url = Url::parse("rtsps://[::1]/test").unwrap();
+        assert_eq!(
+            runtime.block_on(resolve_async(&url)).unwrap(),
+            vec!["[::1]:322".parse().unwrap()]
+        );
+
+        let url = Url::parse("rtsp://127.0.0.1:8554/test").unwrap();
+        assert_eq!(
+            runtime.block_on(resolve_async(&url)).unwrap(),
+            vec!["127.0.0.1:8554".parse().unwrap()]
+        );
+
+        let url = Url::parse("http://127.0.0.1/test").unwrap();
+        assert!(matches!(
+            runtime.block_on(resolve_async(&url)),
+            Err(ResolveError::UnknownPort)
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Error while extracting an SDP session description from a message body.
+#[derive(Debug)]
+pub enum SdpError {
+    /// The message has no `application/sdp` `Content-Type` header.
+    NotSdp,
+    /// The SDP in the body could not be parsed.
+    Parse(sdp::Error),
+}
+
+impl std::error::Error for SdpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            SdpError::NotSdp => None,
+            SdpError::Parse(ref err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for SdpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            SdpError::NotSdp => write!(f, "Body is not SDP"),
+            SdpError::Parse(ref err) => write!(f, "Failed to parse SDP: {err}"),
+        }
+    }
+}
+
+fn parse_sdp(headers: &Headers, body: &[u8]) -> Result<sdp::SessionDescription, SdpError> {
+    let content_type = headers
+        .get_typed::<headers::ContentType>()
+        .ok()
+        .flatten()
+        .ok_or(SdpError::NotSdp)?;
+
+    if content_type.media_type != headers::MediaType::Application
+        || !content_type.media_subtype.eq_ignore_ascii_case("sdp")
+    {
+        return Err(SdpError::NotSdp);
+    }
+
+    sdp::SessionDescription::unmarshal(&mut std::io::Cursor::new(body)).map_err(SdpError::Parse)
+}
+
+impl Request<Empty> {
+    /// Build a new `ANNOUNCE` request for the given URL with the SDP session description as body.
+    ///
+    /// The SDP is serialized, the `Content-Type` header is set to `application/sdp` and the
+    /// `Content-Length` header is set to the length of the serialized SDP. See
+    /// [`announce`](struct.Request.html#method.announce) for passing an already serialized SDP.
+    ///
+    /// ```rust
+    /// let sdp = sdp::SessionDescription::unmarshal(&mut std::io::Cursor::new(
+    ///     "v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=Test\r\nt=0 0\r\n",
+    /// ))
+    /// .expect("Invalid SDP");
+    ///
+    /// let request = rtsp_types::Request::announce_sdp(
+    ///     rtsp_types::Version::V1_0,
+    ///     rtsp_types::Url::parse("rtsp://example.com/test").expect("Invalid URI"),
+    ///     &sdp,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     request.header(&rtsp_types::headers::CONTENT_TYPE).unwrap(),
+    ///     "application/sdp",
+    /// );
+    /// assert!(request.body().starts_with(b"v=0\r\n"));
+    /// ```
+    pub fn announce_sdp(
+        version: Version,
+        url: Url,
+        sdp: &sdp::SessionDescription,
+    ) -> Request<Vec<u8>> {
+        Request::announce(version, url, sdp.marshal().into_bytes())
+    }
+}
+
+/// Parses the SDP body of a request, e.g. an `ANNOUNCE` request.
+///
+/// This fails if the `Content-Type` of the request is not `application/sdp`.
+impl<'a, Body: AsRef<[u8]>> TryFrom<&'a Request<Body>> for sdp::SessionDescription {
+    type Error = SdpError;
+
+    fn try_from(request: &'a Request<Body>) -> Result<Self, SdpError> {
+        parse_sdp(request.as_ref(), request.body().as_ref())
+    }
+}
+
+/// Parses the SDP body of a response, e.g. a `DESCRIBE` response.
+///
+/// This fails if the `Content-Type` of the response is not `application/sdp`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// let sdp = b"v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=Test\r\nt=0 0\r\n";
+/// let response = rtsp_types::Response::builder(
+///     rtsp_types::Version::V2_0,
+///     rtsp_types::StatusCode::Ok,
+/// )
+/// .header(rtsp_types::headers::CONTENT_TYPE, "application/sdp")
+/// .build(&sdp[..]);
+///
+/// let session = sdp::SessionDescription::try_from(&response).expect("Invalid SDP");
+/// assert_eq!(session.session_name, "Test");
+/// ```
+impl<'a, Body: AsRef<[u8]>> TryFrom<&'a Response<Body>> for sdp::SessionDescription {
+    type Error = SdpError;
+
+    fn try_from(response: &'a Response<Body>) -> Result<Self, SdpError> {
+        parse_sdp(response.as_ref(), response.body().as_ref())
+    }
+}
+
+/// Creates an `application/sdp` body.
+///
+/// ```rust
+/// let sdp = sdp::SessionDescription::unmarshal(&mut std::io::Cursor::new(
+///     "v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n",
+/// ))
+/// .expect("Invalid SDP");
+///
+/// let body = rtsp_types::Body::from(sdp);
+/// assert!(matches!(body, rtsp_types::Body::Sdp(ref sdp) if sdp.session_name == "-"));
+/// assert!(body.to_bytes().starts_with(b"v=0\r\n"));
+/// ```
+impl From<sdp::SessionDescription> for Body {
+    fn from(sdp: sdp::SessionDescription) -> Self {
+        Body::Sdp(Box::new(sdp))
+    }
+}
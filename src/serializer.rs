@@ -11,10 +11,7 @@ use cookie_factory as cf;
 use std::io::Write;
 
 fn rtsp_version<W: Write>(version: Version) -> impl SerializeFn<W> {
-    move |out: WriteContext<W>| match version {
-        Version::V1_0 => string("RTSP/1.0")(out),
-        Version::V2_0 => string("RTSP/2.0")(out),
-    }
+    string(version.as_str())
 }
 
 fn method<W: Write>(method: MethodRef<'_>) -> impl SerializeFn<W> + '_ {
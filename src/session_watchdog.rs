@@ -0,0 +1,95 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::time::{Duration, Instant};
+
+/// Tracks the last activity of a session for detecting session timeouts.
+///
+/// A session expires if there was no activity for its timeout, see
+/// [`Session::timeout_duration`](headers/struct.Session.html#method.timeout_duration).
+///
+/// ```rust
+/// let session = rtsp_types::headers::Session::with_timeout(String::from("12345678"), 60);
+/// let mut watchdog = rtsp_types::SessionWatchdog::new(session);
+///
+/// assert!(!watchdog.is_expired());
+///
+/// // Called for every request of the session
+/// watchdog.touch();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionWatchdog {
+    session: headers::Session,
+    last_activity: Instant,
+}
+
+impl SessionWatchdog {
+    /// Creates a new watchdog for the session, starting now.
+    pub fn new(session: headers::Session) -> Self {
+        SessionWatchdog {
+            session,
+            last_activity: Instant::now(),
+        }
+    }
+
+    /// Get the session.
+    pub fn session(&self) -> &headers::Session {
+        &self.session
+    }
+
+    /// Records activity on the session and restarts the timeout.
+    pub fn touch(&mut self) {
+        self.last_activity = Instant::now();
+    }
+
+    /// Get the time that is left until the session expires.
+    pub fn remaining(&self) -> Duration {
+        self.session
+            .timeout_duration()
+            .checked_sub(self.last_activity.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// Checks if the session timeout has elapsed since the last activity.
+    pub fn is_expired(&self) -> bool {
+        self.last_activity.elapsed() >= self.session.timeout_duration()
+    }
+
+    /// Waits until the session expires.
+    ///
+    /// This returns immediately if the session is already expired.
+    #[cfg(feature = "tokio")]
+    pub async fn until_expired(&self) {
+        tokio::time::sleep(self.remaining()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_watchdog() {
+        let watchdog = SessionWatchdog::new(headers::Session(String::from("12345678"), None));
+        assert!(!watchdog.is_expired());
+        assert!(watchdog.remaining() > Duration::from_secs(59));
+
+        let mut watchdog =
+            SessionWatchdog::new(headers::Session::with_timeout(String::from("12345678"), 0));
+        watchdog.touch();
+        assert!(watchdog.is_expired());
+        assert_eq!(watchdog.remaining(), Duration::from_secs(0));
+
+        #[cfg(feature = "tokio")]
+        {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_time()
+                .build()
+                .unwrap();
+            runtime.block_on(watchdog.until_expired());
+        }
+    }
+}
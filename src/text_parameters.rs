@@ -0,0 +1,155 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// `text/parameters` body as used by `GET_PARAMETER` and `SET_PARAMETER` requests and responses.
+///
+/// The body consists of `name: value` lines. Lines with only a parameter name, as used in
+/// `GET_PARAMETER` requests, are stored with an empty value.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// let params = rtsp_types::TextParameters::try_from(&b"jitter: 0.3423\r\npackets_received\r\n"[..])
+///     .expect("Invalid parameters");
+///
+/// assert_eq!(params.get("jitter"), Some("0.3423"));
+/// assert_eq!(params.get("packets_received"), Some(""));
+/// assert_eq!(params.get("unknown"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TextParameters(pub BTreeMap<String, String>);
+
+impl TextParameters {
+    /// Creates new, empty parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the value of the parameter `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    /// Insert a parameter, replacing any previous value.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Iterator over all parameters.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for TextParameters {
+    type Error = InvalidParameterError;
+
+    fn try_from(body: &'a [u8]) -> Result<Self, Self::Error> {
+        let body = std::str::from_utf8(body).map_err(|_| InvalidParameterError)?;
+
+        let mut params = BTreeMap::new();
+        for line in body.split('\n') {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (name, value) = match line.split_once(':') {
+                Some((name, value)) => (name.trim(), value.trim()),
+                None => (line.trim(), ""),
+            };
+
+            if name.is_empty() || !name.bytes().all(parser::is_token_char) {
+                return Err(InvalidParameterError);
+            }
+
+            params.insert(String::from(name), String::from(value));
+        }
+
+        Ok(TextParameters(params))
+    }
+}
+
+impl TextParameters {
+    /// Serializes the parameters.
+    pub(crate) fn to_vec(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (name, value) in &self.0 {
+            body.extend_from_slice(name.as_bytes());
+            if !value.is_empty() {
+                body.extend_from_slice(b": ");
+                body.extend_from_slice(value.as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+        }
+
+        body
+    }
+}
+
+impl From<TextParameters> for Vec<u8> {
+    fn from(params: TextParameters) -> Vec<u8> {
+        params.to_vec()
+    }
+}
+
+/// Creates a `text/parameters` body.
+impl From<TextParameters> for Body {
+    fn from(params: TextParameters) -> Body {
+        Body::TextParameters(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_parameters() {
+        let params =
+            TextParameters::try_from(&b"packets_received: 10\njitter:0.3838\r\n\r\nscale\r\n"[..])
+                .unwrap();
+
+        assert_eq!(params.get("packets_received"), Some("10"));
+        assert_eq!(params.get("jitter"), Some("0.3838"));
+        assert_eq!(params.get("scale"), Some(""));
+
+        assert_eq!(
+            Vec::<u8>::from(params.clone()),
+            b"jitter: 0.3838\r\npackets_received: 10\r\nscale\r\n".to_vec()
+        );
+
+        let body = Body::from(params.clone());
+        assert_eq!(body, Body::TextParameters(params));
+        assert_eq!(
+            body.to_bytes(),
+            &b"jitter: 0.3838\r\npackets_received: 10\r\nscale\r\n"[..]
+        );
+        assert_eq!(body.byte_len(), 45);
+        let content_type = body.content_type().unwrap();
+        assert_eq!(content_type.media_type, headers::MediaType::Text);
+        assert_eq!(content_type.media_subtype, "parameters");
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok).body(body);
+        assert_eq!(
+            response.header(&headers::CONTENT_TYPE).unwrap(),
+            "text/parameters"
+        );
+        assert_eq!(response.header(&headers::CONTENT_LENGTH).unwrap(), "45");
+
+        assert_eq!(
+            TextParameters::try_from(&b"invalid name: 1\r\n"[..]),
+            Err(InvalidParameterError)
+        );
+        assert_eq!(
+            TextParameters::try_from(&b"name: \xff\r\n"[..]),
+            Err(InvalidParameterError)
+        );
+    }
+}